@@ -0,0 +1,246 @@
+//! Downloads of the files attached to [comments](crate::model::comment::Comment)
+//!
+//! Files are streamed to their destination instead of being read into memory,
+//! so the [middleware](crate::middleware) of the config does not apply to downloads.
+//! The token of the config is only sent to Todoist domains.
+
+use std::path::{Path, PathBuf};
+
+use reqwest::header::AUTHORIZATION;
+use reqwest::Url;
+use sha2::{Digest, Sha256};
+use tokio::fs::File;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use uuid::Uuid;
+
+use crate::internal::request::{check_response_status, APIParametersError, ChecksumMismatchError};
+use crate::model::comment::CommentAttachment;
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
+/// The result of downloading an attachment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadedFile {
+    /// The number of bytes that were downloaded
+    pub size: u64,
+    /// The SHA-256 checksum of the downloaded bytes, in lowercase hexadecimal
+    pub sha256: String,
+}
+
+/// Downloads the file of an attachment into `writer`
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn download_attachment<W: AsyncWrite + Unpin + ?Sized>(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    writer: &mut W,
+) -> Result<DownloadedFile, TodoistAPIError> {
+    let url = Url::parse(&attachment.file_url).map_err(|e| APIParametersError {
+        message: format!("Invalid attachment URL {} - {}", attachment.file_url, e),
+    })?;
+    let mut request = config.client.get(url.clone());
+    if is_todoist_url(&url) {
+        let authorization = config
+            .get_authorization_header()
+            .map_err(|e| APIParametersError {
+                message: e.to_string(),
+            })?;
+        request = request.header(AUTHORIZATION, authorization);
+    }
+    let mut response = request.send().await?;
+    check_response_status(&response)?;
+    let mut hasher = Sha256::new();
+    let mut size = 0;
+    while let Some(chunk) = response.chunk().await? {
+        hasher.update(&chunk);
+        writer.write_all(&chunk).await?;
+        size += chunk.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(DownloadedFile {
+        size,
+        sha256: to_hex(&hasher.finalize()),
+    })
+}
+
+/// Downloads the file of an attachment to `path`, creating its parent directories
+///
+/// The file is written to a temporary file next to `path`, unique to this download,
+/// and only moved to `path` once it is complete.
+/// If `expected_sha256` is [Some] and does not match the downloaded file,
+/// the file is deleted and a [ChecksumMismatch](TodoistAPIError::ChecksumMismatch) error is returned.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn download_attachment_to_path(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    path: &Path,
+    expected_sha256: Option<&str>,
+) -> Result<DownloadedFile, TodoistAPIError> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    // Concurrent downloads to the same path each write their own file
+    let mut partial_path = path.as_os_str().to_owned();
+    partial_path.push(format!(".{}.part", Uuid::new_v4().simple()));
+    let partial_path = PathBuf::from(partial_path);
+    let result = async {
+        let mut file = File::create(&partial_path).await?;
+        let downloaded = download_attachment(config, attachment, &mut file).await?;
+        if let Some(expected_sha256) = expected_sha256 {
+            if !downloaded.sha256.eq_ignore_ascii_case(expected_sha256) {
+                return Err(ChecksumMismatchError {
+                    file_name: attachment.file_name.clone(),
+                    expected: expected_sha256.to_string(),
+                    actual: downloaded.sha256,
+                }
+                .into());
+            }
+        }
+        Ok(downloaded)
+    }
+    .await;
+    match result {
+        Ok(downloaded) => {
+            tokio::fs::rename(&partial_path, path).await?;
+            Ok(downloaded)
+        }
+        Err(error) => {
+            let _ = tokio::fs::remove_file(&partial_path).await;
+            Err(error)
+        }
+    }
+}
+
+/// Downloads the file of an attachment into `cache_directory` unless it was already downloaded,
+/// returning the path of the file
+///
+/// Files are stored by the checksum of their URL, so an attachment is only downloaded once.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn download_attachment_cached(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    cache_directory: &Path,
+) -> Result<PathBuf, TodoistAPIError> {
+    let path = get_cache_path(attachment, cache_directory);
+    if !tokio::fs::try_exists(&path).await? {
+        download_attachment_to_path(config, attachment, &path, None).await?;
+    }
+    Ok(path)
+}
+
+fn get_cache_path(attachment: &CommentAttachment, cache_directory: &Path) -> PathBuf {
+    // Only the last component is kept, so that the file cannot be written outside of the cache
+    let file_name = Path::new(&attachment.file_name)
+        .file_name()
+        .map(|name| name.to_owned())
+        .unwrap_or_else(|| "attachment".into());
+    cache_directory
+        .join(to_hex(&Sha256::digest(attachment.file_url.as_bytes())))
+        .join(file_name)
+}
+
+fn is_todoist_url(url: &Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| host == "todoist.com" || host.ends_with(".todoist.com"))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::model::comment::AttachmentResourceType;
+
+    /// Serves `body` to every request, returning the URL of the server
+    async fn serve(body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).await.unwrap();
+                let headers = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(headers.as_bytes()).await.unwrap();
+                stream.write_all(body).await.unwrap();
+            }
+        });
+        format!("http://{}/File.pdf", address)
+    }
+
+    fn create_attachment(file_url: String) -> CommentAttachment {
+        CommentAttachment {
+            file_name: "File.pdf".into(),
+            file_type: "application/pdf".into(),
+            file_url,
+            resource_type: AttachmentResourceType::File,
+            extra: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn downloads_and_verifies_checksums() {
+        let config = TodoistConfig::new("0123456789abcdef").unwrap();
+        let attachment = create_attachment(serve(b"Buy Milk").await);
+        let mut content = Vec::new();
+        let downloaded = download_attachment(&config, &attachment, &mut content)
+            .await
+            .unwrap();
+        assert_eq!(content, b"Buy Milk");
+        assert_eq!(downloaded.size, 8);
+        assert_eq!(downloaded.sha256, to_hex(&Sha256::digest(b"Buy Milk")));
+
+        let directory = std::env::temp_dir().join(format!("todoist-{}", uuid::Uuid::new_v4()));
+        let path = directory.join("File.pdf");
+        let error = download_attachment_to_path(&config, &attachment, &path, Some(&"0".repeat(64)))
+            .await
+            .unwrap_err();
+        match error {
+            TodoistAPIError::ChecksumMismatch(error) => {
+                assert_eq!(error.expected(), "0".repeat(64));
+                assert_eq!(error.actual(), downloaded.sha256);
+            }
+            error => panic!("unexpected error {:?}", error),
+        }
+        assert!(!path.exists());
+        assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 0);
+        download_attachment_to_path(&config, &attachment, &path, Some(&downloaded.sha256))
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"Buy Milk");
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn cache_paths_stay_in_the_cache() {
+        let mut attachment = create_attachment("https://files.todoist.com/a".into());
+        attachment.file_name = "../../.bashrc".into();
+        let path = get_cache_path(&attachment, Path::new("/cache"));
+        assert_eq!(
+            path.parent().unwrap().parent().unwrap(),
+            Path::new("/cache")
+        );
+        assert_eq!(path.file_name().unwrap(), ".bashrc");
+    }
+
+    #[test]
+    fn token_is_only_sent_to_todoist() {
+        let is_todoist = |url: &str| is_todoist_url(&Url::parse(url).unwrap());
+        assert!(is_todoist("https://files.todoist.com/a/File.pdf"));
+        assert!(is_todoist("https://todoist.com/a"));
+        assert!(!is_todoist("http://files.todoist.com/a"));
+        assert!(!is_todoist("https://todoist.com.example.com/a"));
+        assert!(!is_todoist("https://cdn-domain.tld/path/to/file.pdf"));
+    }
+}
@@ -0,0 +1,407 @@
+//! Helpers that apply the same operation to many tasks
+//!
+//! The requests are sent concurrently (up to `concurrency` at the same time),
+//! and still go through the [rate limiter](crate::middleware::RateLimiter) and
+//! [retry policy](crate::middleware::RetryPolicy) of the config.
+//! If the config has no retry policy, requests rejected because of rate limiting
+//! are retried with the [default retry policy](RetryPolicy::default),
+//! as many concurrent requests are likely to reach the rate limit.
+//!
+//! Every helper returns one result per item, in the same order as the items,
+//! so a failed request does not stop the other ones.
+
+use std::future::Future;
+
+use futures_util::stream::{self, StreamExt};
+use reqwest::StatusCode;
+
+use crate::internal::request::TodoistAPIError;
+use crate::middleware::RetryPolicy;
+use crate::model::task::{Task, TaskDuration};
+use crate::todoist_config::TodoistConfig;
+
+/// The default number of requests that the bulk helpers send at the same time
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A task to create with [create_tasks]
+///
+/// See [crate::tasks::create_new_task] for the meaning of each field.
+#[derive(Debug, Clone, Default)]
+pub struct NewTask {
+    /// The content of the task
+    pub content: String,
+    /// The description of the task
+    pub description: Option<String>,
+    /// The ID of the project to add the task to
+    pub project_id: Option<String>,
+    /// The ID of the section to add the task to
+    pub section_id: Option<String>,
+    /// The ID of the parent task
+    pub parent_id: Option<String>,
+    /// The position of the task among the tasks with the same parent
+    pub order: Option<u32>,
+    /// The names of the labels of the task
+    pub labels: Option<Vec<String>>,
+    /// The priority of the task (from `1` (normal) to `4` (urgent))
+    pub priority: Option<u8>,
+    /// The due date in natural language (ex: `next Monday`)
+    pub due_string: Option<String>,
+    /// The due date in the format `YYYY-MM-DD`
+    pub due_date: Option<String>,
+    /// The due date and time in RFC3339 format
+    pub due_datetime: Option<String>,
+    /// The 2-letter code of the language of `due_string`
+    pub due_lang: Option<String>,
+    /// The ID of the user responsible for the task
+    pub assignee_id: Option<String>,
+    /// The time that the task will take
+    pub duration: Option<TaskDuration>,
+}
+
+/// Changes to apply to a task with [update_tasks]
+///
+/// See [crate::tasks::update_task] for the meaning of each field.
+#[derive(Debug, Clone, Default)]
+pub struct TaskUpdate {
+    /// The ID of the task to update
+    pub task_id: String,
+    /// The new content of the task
+    pub content: Option<String>,
+    /// The new description of the task
+    pub description: Option<String>,
+    /// The new names of the labels of the task
+    pub labels: Option<Vec<String>>,
+    /// The new priority of the task (from `1` (normal) to `4` (urgent))
+    pub priority: Option<u8>,
+    /// The new due date in natural language (ex: `next Monday`)
+    pub due_string: Option<String>,
+    /// The new due date in the format `YYYY-MM-DD`
+    pub due_date: Option<String>,
+    /// The new due date and time in RFC3339 format
+    pub due_datetime: Option<String>,
+    /// The 2-letter code of the language of `due_string`
+    pub due_lang: Option<String>,
+    /// The ID of the user responsible for the task
+    pub assignee_id: Option<String>,
+    /// The new time that the task will take
+    pub duration: Option<TaskDuration>,
+}
+
+/// Closes every task in `task_ids`
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn close_tasks(
+    config: &TodoistConfig,
+    task_ids: Vec<String>,
+    concurrency: usize,
+) -> Vec<Result<(), TodoistAPIError>> {
+    run_concurrently(config, task_ids, concurrency, |task_id| {
+        crate::tasks::close_task(config, task_id)
+    })
+    .await
+}
+
+/// Deletes every task in `task_ids`
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn delete_tasks(
+    config: &TodoistConfig,
+    task_ids: Vec<String>,
+    concurrency: usize,
+) -> Vec<Result<(), TodoistAPIError>> {
+    run_concurrently(config, task_ids, concurrency, |task_id| {
+        crate::tasks::delete_task(config, task_id)
+    })
+    .await
+}
+
+/// Creates every task in `tasks`
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn create_tasks(
+    config: &TodoistConfig,
+    tasks: Vec<NewTask>,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    run_concurrently(config, tasks, concurrency, |task| {
+        crate::tasks::create_new_task(
+            config,
+            task.content,
+            task.description,
+            task.project_id,
+            task.section_id,
+            task.parent_id,
+            task.order,
+            task.labels,
+            task.priority,
+            task.due_string,
+            task.due_date,
+            task.due_datetime,
+            task.due_lang,
+            task.assignee_id,
+            task.duration,
+        )
+    })
+    .await
+}
+
+/// Applies every update in `updates`
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn update_tasks(
+    config: &TodoistConfig,
+    updates: Vec<TaskUpdate>,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    run_concurrently(config, updates, concurrency, |update| {
+        crate::tasks::update_task(
+            config,
+            update.task_id,
+            update.content,
+            update.description,
+            update.labels,
+            update.priority,
+            update.due_string,
+            update.due_date,
+            update.due_datetime,
+            update.due_lang,
+            update.assignee_id,
+            update.duration,
+        )
+    })
+    .await
+}
+
+/// Adds the label named `label` to every task in `tasks`
+///
+/// Each task is read again right before it is updated, so labels changed since `tasks` were fetched are kept.
+/// The REST API can only replace all the labels of a task, so labels changed by another client
+/// between that read and the update are still overwritten.
+/// Tasks that already have the label are returned without updating them.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn add_label_to_tasks(
+    config: &TodoistConfig,
+    tasks: Vec<Task>,
+    label: String,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    run_concurrently(config, tasks, concurrency, |task| {
+        let label = label.clone();
+        async move {
+            let mut task = crate::tasks::get_active_task(config, task.id).await?;
+            if task.labels.contains(&label) {
+                return Ok(task);
+            }
+            task.labels.push(label);
+            crate::tasks::update_task(
+                config,
+                task.id,
+                None,
+                None,
+                Some(task.labels),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+        }
+    })
+    .await
+}
+
+/// Calls `operation` for each item with at most `concurrency` calls running at the same time,
+/// returning the results in the same order as the items
+async fn run_concurrently<I, T, F, Fut>(
+    config: &TodoistConfig,
+    items: Vec<I>,
+    concurrency: usize,
+    operation: F,
+) -> Vec<Result<T, TodoistAPIError>>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = Result<T, TodoistAPIError>>,
+{
+    let operation = &operation;
+    stream::iter(items)
+        .map(|item| retry_rate_limited(config, move || operation(item.clone())))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Calls `operation` until it is not rejected because of rate limiting,
+/// waiting between calls according to the [default retry policy](RetryPolicy::default)
+///
+/// Configs with a [RetryPolicy] already retry rate-limited requests, so `operation` is only called once.
+async fn retry_rate_limited<T, F, Fut>(
+    config: &TodoistConfig,
+    operation: F,
+) -> Result<T, TodoistAPIError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, TodoistAPIError>>,
+{
+    let retry_policy = RetryPolicy::default();
+    let mut retries = 0;
+    loop {
+        let result = operation().await;
+        let is_rate_limited = matches!(
+            &result,
+            Err(TodoistAPIError::InvalidRequest(error))
+                if error.status_code.get() == StatusCode::TOO_MANY_REQUESTS.as_u16()
+        );
+        if config.retry_policy.is_some() || !is_rate_limited || retries >= retry_policy.max_retries
+        {
+            return result;
+        }
+        retries += 1;
+        tokio::time::sleep(retry_policy.get_backoff(retries, None)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    #[tokio::test]
+    async fn results_keep_order_and_concurrency_is_bounded() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+        let config = TodoistConfig::new("0123456789abcdef").unwrap();
+        let results = run_concurrently(&config, (0..20).collect(), 3, |i: u64| {
+            let running = &running;
+            let max_running = &max_running;
+            async move {
+                let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now_running, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(20 - i)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                if i.is_multiple_of(5) {
+                    Err(crate::internal::request::APIParametersError {
+                        message: i.to_string(),
+                    }
+                    .into())
+                } else {
+                    Ok(i)
+                }
+            }
+        })
+        .await;
+        assert_eq!(results.len(), 20);
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(value) => assert_eq!(value, i as u64),
+                Err(_) => assert!(i.is_multiple_of(5)),
+            }
+        }
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    /// Rejects the first request because of rate limiting, and accepts every other request
+    struct RateLimitedOnce {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl Middleware for RateLimitedOnce {
+        async fn before_request(
+            &self,
+            _request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let status = match self.requests.fetch_add(1, Ordering::SeqCst) {
+                0 => StatusCode::TOO_MANY_REQUESTS,
+                _ => StatusCode::NO_CONTENT,
+            };
+            Ok(Some(TodoistResponse {
+                status,
+                headers: HeaderMap::new(),
+                body: Vec::new(),
+            }))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limited_requests_are_retried_without_retry_policy() {
+        let middleware = Arc::new(RateLimitedOnce {
+            requests: AtomicUsize::new(0),
+        });
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(middleware.clone());
+        let results = close_tasks(&config, vec!["1".into(), "2".into()], 1).await;
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(middleware.requests.load(Ordering::SeqCst), 3);
+    }
+
+    /// A single task whose labels can be read and replaced
+    struct TaskEndpoint {
+        labels: Mutex<Vec<String>>,
+    }
+
+    impl TaskEndpoint {
+        fn get_task_json(&self) -> serde_json::Value {
+            serde_json::json!({
+                "id": "2995104339",
+                "project_id": "2203306141",
+                "section_id": null,
+                "parent_id": null,
+                "content": "Buy Milk",
+                "description": "",
+                "is_completed": false,
+                "labels": *self.labels.lock().unwrap(),
+                "order": 1,
+                "priority": 1,
+                "due": null,
+                "url": "https://todoist.com/showTask?id=2995104339",
+                "comment_count": 0,
+                "created_at": "2019-12-11T22:36:50.000000Z",
+                "creator_id": "2671355",
+                "assignee_id": null,
+                "assigner_id": null,
+                "duration": null
+            })
+        }
+    }
+
+    #[async_trait]
+    impl Middleware for TaskEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            if let Some(body) = &request.body {
+                let body: serde_json::Value = serde_json::from_slice(body).unwrap();
+                *self.labels.lock().unwrap() =
+                    serde_json::from_value(body["labels"].clone()).unwrap();
+            }
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&self.get_task_json()).unwrap(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn added_labels_keep_the_current_labels() {
+        let endpoint = Arc::new(TaskEndpoint {
+            labels: Mutex::new(Vec::new()),
+        });
+        let stale_task: Task = serde_json::from_value(endpoint.get_task_json()).unwrap();
+        endpoint.labels.lock().unwrap().push("Food".into());
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(endpoint.clone());
+        let results = add_label_to_tasks(&config, vec![stale_task], "Shopping".into(), 1).await;
+        assert_eq!(results[0].as_ref().unwrap().labels, ["Food", "Shopping"]);
+        assert_eq!(*endpoint.labels.lock().unwrap(), ["Food", "Shopping"]);
+    }
+}
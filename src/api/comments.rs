@@ -1,6 +1,9 @@
 //! Todoist Comments API (<https://developer.todoist.com/rest/v2/?shell#comments>)
 
+use futures_util::Stream;
+
 use crate::internal::request::models::{CreateNewCommentArgs, UpdateCommentArgs};
+use crate::internal::request::pagination::{get_all_results, stream_results};
 use crate::internal::request::paths::create_path;
 use crate::internal::request::{
     paths, send_todoist_delete_request, send_todoist_get_request, send_todoist_post_request,
@@ -11,28 +14,37 @@ use crate::todoist_config::TodoistConfig;
 
 /// Get all [comments](Comment) from a [task](crate::model::task::Task)
 /// or [project](crate::model::project::Project)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_comments(
     config: &TodoistConfig,
     task_or_project_id: &TaskOrProjectID,
 ) -> Result<Vec<Comment>, TodoistAPIError> {
-    send_todoist_get_request(
-        config,
-        format!(
-            "{}?{}={}",
-            paths::COMMENTS,
-            task_or_project_id.get_key(),
-            task_or_project_id.get_id()
-        ),
-    )
-    .await
+    get_all_results(config, get_all_comments_path(task_or_project_id)).await
+}
+
+/// Get all [comments](Comment) from a [task](crate::model::task::Task)
+/// or [project](crate::model::project::Project) as a [Stream]
+///
+/// With API v1, the comments are requested page by page as the stream is consumed.
+/// API v2 returns every comment in a single response, which is read in full first.
+pub fn stream_all_comments<'a>(
+    config: &'a TodoistConfig,
+    task_or_project_id: &TaskOrProjectID,
+) -> impl Stream<Item = Result<Comment, TodoistAPIError>> + 'a {
+    stream_results(config, get_all_comments_path(task_or_project_id))
 }
 
 /// Creates a new [comment](Comment) on a project or task
+///
+/// The users in `uids_to_notify` (ex: the collaborators mentioned in the comment) are notified
+/// of the new comment.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn create_new_comment(
     config: &TodoistConfig,
     task_or_project_id: &TaskOrProjectID,
     content: String,
     attachment: Option<CommentAttachment>,
+    uids_to_notify: Option<Vec<String>>,
 ) -> Result<Comment, TodoistAPIError> {
     send_todoist_post_request(
         config,
@@ -48,6 +60,7 @@ pub async fn create_new_comment(
             },
             content,
             attachment,
+            uids_to_notify,
         }),
         true,
     )
@@ -55,6 +68,7 @@ pub async fn create_new_comment(
 }
 
 /// Gets a single [comment](Comment)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_comment(
     config: &TodoistConfig,
     comment_id: String,
@@ -63,6 +77,7 @@ pub async fn get_comment(
 }
 
 /// Updates a [comment](Comment)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn update_comment(
     config: &TodoistConfig,
     comment_id: String,
@@ -78,6 +93,7 @@ pub async fn update_comment(
 }
 
 /// Deletes a [comment](Comment)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn delete_comment(
     config: &TodoistConfig,
     comment_id: String,
@@ -85,6 +101,15 @@ pub async fn delete_comment(
     send_todoist_delete_request(config, get_comment_path(comment_id)).await
 }
 
+fn get_all_comments_path(task_or_project_id: &TaskOrProjectID) -> String {
+    format!(
+        "{}?{}={}",
+        paths::COMMENTS,
+        task_or_project_id.get_key(),
+        task_or_project_id.get_id()
+    )
+}
+
 fn get_comment_path(comment_id: String) -> String {
     create_path(&[paths::COMMENTS, &comment_id])
 }
@@ -114,3 +139,74 @@ impl TaskOrProjectID {
         .to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    /// Answers every request with the same comment, keeping the body of each request
+    struct CommentsEndpoint {
+        bodies: Mutex<Vec<serde_json::Value>>,
+    }
+
+    #[async_trait]
+    impl Middleware for CommentsEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            assert_eq!(request.url, "https://api.todoist.com/rest/v2/comments");
+            self.bodies
+                .lock()
+                .unwrap()
+                .push(serde_json::from_slice(request.body.as_ref().unwrap()).unwrap());
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: br#"{"id": "2992679862", "task_id": "2995104339", "project_id": null,
+                    "content": "Need one bottle of milk", "posted_at": "2016-09-22T07:00:00.000000Z",
+                    "attachment": null}"#
+                    .to_vec(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn users_to_notify_are_sent() {
+        let endpoint = Arc::new(CommentsEndpoint {
+            bodies: Mutex::new(Vec::new()),
+        });
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(endpoint.clone());
+        let task_id = TaskOrProjectID::Task("2995104339".into());
+        for uids_to_notify in [Some(vec!["2671362".into(), "2671366".into()]), None] {
+            create_new_comment(
+                &config,
+                &task_id,
+                "Need one bottle of milk".into(),
+                None,
+                uids_to_notify,
+            )
+            .await
+            .unwrap();
+        }
+        let bodies = endpoint.bodies.lock().unwrap();
+        assert_eq!(
+            bodies[0],
+            serde_json::json!({
+                "task_id": "2995104339",
+                "content": "Need one bottle of milk",
+                "uids_to_notify": ["2671362", "2671366"],
+            })
+        );
+        assert!(bodies[1].get("uids_to_notify").is_none());
+    }
+}
@@ -0,0 +1,306 @@
+//! Higher-level operations on [personal labels](PersonalLabel) and shared labels
+//!
+//! These helpers combine the requests of [crate::labels] and [crate::tasks],
+//! and return a report of what they changed.
+
+use std::collections::HashSet;
+
+use crate::bulk::{update_tasks, TaskUpdate};
+use crate::internal::request::{APIParametersError, TodoistAPIError};
+use crate::model::label::PersonalLabel;
+use crate::todoist_config::TodoistConfig;
+
+/// What [merge_labels] changed
+#[derive(Debug)]
+pub struct LabelMergeReport {
+    /// The IDs of the tasks that now have the merged label instead of the old one
+    pub updated_task_ids: Vec<String>,
+    /// The IDs of the tasks that could not be updated, with the error of each task
+    pub failed_tasks: Vec<(String, TodoistAPIError)>,
+    /// The personal label that was deleted ([None] if the old label was not a personal label,
+    /// or if some tasks could not be updated)
+    pub deleted_personal_label: Option<PersonalLabel>,
+}
+
+/// What [rename_label_everywhere] changed
+#[derive(Debug, Clone)]
+pub struct LabelRenameReport {
+    /// The renamed personal label ([None] if there was no personal label with the old name)
+    pub personal_label: Option<PersonalLabel>,
+    /// The IDs of the active tasks that had the label before it was renamed
+    pub task_ids: Vec<String>,
+}
+
+/// The labels found by [find_orphan_labels]
+#[derive(Debug, Clone)]
+pub struct OrphanLabels {
+    /// The names of the labels assigned to active tasks that have no personal label
+    pub shared_without_personal: Vec<String>,
+    /// The personal labels that are not assigned to any active task
+    pub unused_personal_labels: Vec<PersonalLabel>,
+}
+
+/// Replaces the label named `from` with the label named `into` on every active task
+///
+/// Once every task is updated, the personal label named `from` (if any) is deleted.
+/// Tasks are updated with up to `concurrency` requests at the same time.
+///
+/// Completed tasks cannot be updated, so they keep the label named `from`.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn merge_labels(
+    config: &TodoistConfig,
+    from: String,
+    into: String,
+    concurrency: usize,
+) -> Result<LabelMergeReport, TodoistAPIError> {
+    if from == into {
+        return Err(APIParametersError {
+            message: format!("Cannot merge the label {} into itself", from),
+        }
+        .into());
+    }
+    let tasks =
+        crate::tasks::get_active_tasks(config, None, None, Some(from.clone()), None, None, None)
+            .await?;
+    let updates: Vec<TaskUpdate> = tasks
+        .into_iter()
+        .filter_map(|task| {
+            merge_task_labels(&task.labels, &from, &into).map(|labels| TaskUpdate {
+                task_id: task.id,
+                labels: Some(labels),
+                ..TaskUpdate::default()
+            })
+        })
+        .collect();
+    let task_ids: Vec<String> = updates
+        .iter()
+        .map(|update| update.task_id.clone())
+        .collect();
+    let mut report = LabelMergeReport {
+        updated_task_ids: Vec::new(),
+        failed_tasks: Vec::new(),
+        deleted_personal_label: None,
+    };
+    for (task_id, result) in task_ids
+        .into_iter()
+        .zip(update_tasks(config, updates, concurrency).await)
+    {
+        match result {
+            Ok(_) => report.updated_task_ids.push(task_id),
+            Err(error) => report.failed_tasks.push((task_id, error)),
+        }
+    }
+    if report.failed_tasks.is_empty() {
+        if let Some(label) = find_personal_label(config, &from).await? {
+            crate::labels::delete_personal_label(config, label.id.clone()).await?;
+            report.deleted_personal_label = Some(label);
+        }
+    }
+    Ok(report)
+}
+
+/// Renames a label everywhere, both as a personal label and as a shared label
+///
+/// If a personal label named `new_name` already exists, an error is returned
+/// without renaming anything, as the labels should be merged with [merge_labels] instead.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn rename_label_everywhere(
+    config: &TodoistConfig,
+    name: String,
+    new_name: String,
+) -> Result<LabelRenameReport, TodoistAPIError> {
+    let personal_labels = crate::labels::get_all_personal_labels(config).await?;
+    if personal_labels.iter().any(|label| label.name == new_name) {
+        return Err(APIParametersError {
+            message: format!(
+                "A personal label named {} already exists, merge the labels instead",
+                new_name
+            ),
+        }
+        .into());
+    }
+    let task_ids =
+        crate::tasks::get_active_tasks(config, None, None, Some(name.clone()), None, None, None)
+            .await?
+            .into_iter()
+            .map(|task| task.id)
+            .collect();
+    let personal_label = match personal_labels.into_iter().find(|label| label.name == name) {
+        Some(label) => Some(
+            crate::labels::update_personal_label(
+                config,
+                label.id,
+                Some(new_name.clone()),
+                None,
+                None,
+                None,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    // Renames the instances that are not linked to the personal label (ex: on tasks of collaborators)
+    crate::labels::rename_shared_labels(config, name, new_name).await?;
+    Ok(LabelRenameReport {
+        personal_label,
+        task_ids,
+    })
+}
+
+/// Finds the labels of active tasks that have no personal label, and the personal labels
+/// that are not assigned to any active task
+///
+/// The labels of active tasks are read with [crate::labels::get_all_shared_labels],
+/// so the tasks themselves are not requested.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn find_orphan_labels(config: &TodoistConfig) -> Result<OrphanLabels, TodoistAPIError> {
+    let personal_labels = crate::labels::get_all_personal_labels(config).await?;
+    let task_labels = crate::labels::get_all_shared_labels(config, None).await?;
+    Ok(compute_orphan_labels(personal_labels, &task_labels))
+}
+
+/// Finds the orphan labels from the personal labels and the names of the labels of active tasks
+/// (see [find_orphan_labels])
+pub fn compute_orphan_labels(
+    personal_labels: Vec<PersonalLabel>,
+    task_labels: &[String],
+) -> OrphanLabels {
+    let personal_names: HashSet<&str> = personal_labels
+        .iter()
+        .map(|label| label.name.as_str())
+        .collect();
+    let mut seen = HashSet::new();
+    let shared_without_personal = task_labels
+        .iter()
+        .filter(|name| !personal_names.contains(name.as_str()) && seen.insert(name.as_str()))
+        .cloned()
+        .collect();
+    let task_labels: HashSet<&str> = task_labels.iter().map(String::as_str).collect();
+    OrphanLabels {
+        shared_without_personal,
+        unused_personal_labels: personal_labels
+            .into_iter()
+            .filter(|label| !task_labels.contains(label.name.as_str()))
+            .collect(),
+    }
+}
+
+/// Returns the labels of a task with `from` replaced by `into`,
+/// or [None] if the task does not have the label `from`
+fn merge_task_labels(labels: &[String], from: &str, into: &str) -> Option<Vec<String>> {
+    if !labels.iter().any(|label| label == from) {
+        return None;
+    }
+    let mut merged = Vec::with_capacity(labels.len());
+    for label in labels {
+        let label = if label == from { into } else { label };
+        if !merged.iter().any(|merged_label| merged_label == label) {
+            merged.push(label.to_string());
+        }
+    }
+    Some(merged)
+}
+
+async fn find_personal_label(
+    config: &TodoistConfig,
+    name: &str,
+) -> Result<Option<PersonalLabel>, TodoistAPIError> {
+    Ok(crate::labels::get_all_personal_labels(config)
+        .await?
+        .into_iter()
+        .find(|label| label.name == name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+    use crate::model::color::Color;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn personal_label(name: &str) -> PersonalLabel {
+        PersonalLabel {
+            id: format!("id-{}", name),
+            name: name.into(),
+            color: Color::Charcoal,
+            order: 1,
+            is_favorite: false,
+            extra: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn merged_labels_keep_their_position_without_duplicates() {
+        assert_eq!(
+            merge_task_labels(&labels(&["a", "errand", "b"]), "errand", "errands"),
+            Some(labels(&["a", "errands", "b"]))
+        );
+        assert_eq!(
+            merge_task_labels(&labels(&["errands", "errand"]), "errand", "errands"),
+            Some(labels(&["errands"]))
+        );
+        assert_eq!(
+            merge_task_labels(&labels(&["a"]), "errand", "errands"),
+            None
+        );
+    }
+
+    #[test]
+    fn orphans_are_found_both_ways() {
+        let orphans = compute_orphan_labels(
+            vec![personal_label("Food"), personal_label("Work")],
+            &labels(&["Food", "Errands", "Errands", "Shopping"]),
+        );
+        assert_eq!(
+            orphans.shared_without_personal,
+            labels(&["Errands", "Shopping"])
+        );
+        assert_eq!(orphans.unused_personal_labels.len(), 1);
+        assert_eq!(orphans.unused_personal_labels[0].name, "Work");
+    }
+
+    /// Answers the requests for personal and shared labels, and fails on any other request
+    struct LabelsEndpoint;
+
+    #[async_trait]
+    impl Middleware for LabelsEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let body = match request.url.as_str() {
+                "https://api.todoist.com/rest/v2/labels" => {
+                    r#"[{"id": "1", "name": "Food", "color": "charcoal", "order": 1, "is_favorite": false},
+                        {"id": "2", "name": "Work", "color": "charcoal", "order": 2, "is_favorite": false}]"#
+                }
+                "https://api.todoist.com/rest/v2/labels/shared" => r#"["Food", "Errands"]"#,
+                url => panic!("unexpected URL {}", url),
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.as_bytes().to_vec(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn orphans_are_found_without_reading_tasks() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(LabelsEndpoint);
+        let orphans = find_orphan_labels(&config).await.unwrap();
+        assert_eq!(orphans.shared_without_personal, labels(&["Errands"]));
+        assert_eq!(orphans.unused_personal_labels.len(), 1);
+        assert_eq!(orphans.unused_personal_labels[0].name, "Work");
+    }
+}
@@ -11,6 +11,7 @@ use crate::internal::request::models::{
     CreateNewPersonalLabelArgs, RemoveSharedLabelsArgs, RenameSharedLabelsArgs,
     UpdatePersonalLabelArgs,
 };
+use crate::internal::request::pagination::get_all_results;
 use crate::internal::request::paths::create_path;
 use crate::internal::request::{
     paths, send_todoist_delete_request, send_todoist_get_request, send_todoist_post_request,
@@ -21,10 +22,11 @@ use crate::model::label::PersonalLabel;
 use crate::todoist_config::TodoistConfig;
 
 /// Get all personal user [labels](PersonalLabel)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_personal_labels(
     config: &TodoistConfig,
 ) -> Result<Vec<PersonalLabel>, TodoistAPIError> {
-    send_todoist_get_request(config, paths::LABELS.into()).await
+    get_all_results(config, paths::LABELS.into()).await
 }
 
 /// Create a new personal [PersonalLabel]
@@ -34,6 +36,7 @@ pub async fn get_all_personal_labels(
 /// * `order` - The number used by clients to sort the list of labels (optional)
 /// * `color` - The [color](Color) of the label icon (optional)
 /// * `is_favorite` - Whether the label is a favorite (optional)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn create_new_personal_label(
     config: &TodoistConfig,
     name: String,
@@ -56,6 +59,7 @@ pub async fn create_new_personal_label(
 }
 
 /// Get a personal [PersonalLabel] by ID
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_personal_label(
     config: &TodoistConfig,
     label_id: String,
@@ -64,6 +68,7 @@ pub async fn get_personal_label(
 }
 
 /// Update a personal [PersonalLabel]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn update_personal_label(
     config: &TodoistConfig,
     label_id: String,
@@ -89,6 +94,7 @@ pub async fn update_personal_label(
 /// Delete a personal [PersonalLabel]
 ///
 /// Deleting a [PersonalLabel] will remove all instances of it from tasks
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn delete_personal_label(
     config: &TodoistConfig,
     label_id: String,
@@ -101,11 +107,12 @@ pub async fn delete_personal_label(
 /// By default, the names of a user's **personal** labels will also be included.
 ///
 /// These can be excluded by setting the `omit_personal` parameter to `true`.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_shared_labels(
     config: &TodoistConfig,
     omit_personal: Option<bool>,
 ) -> Result<Vec<String>, TodoistAPIError> {
-    send_todoist_get_request(
+    get_all_results(
         config,
         format!(
             "{}{}",
@@ -124,6 +131,7 @@ pub async fn get_all_shared_labels(
 /// # Arguments
 /// * `name` - The name of the existing label to rename
 /// * `new_name` - The new name for the label
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn rename_shared_labels(
     config: &TodoistConfig,
     name: String,
@@ -141,6 +149,7 @@ pub async fn rename_shared_labels(
 /// Remove all instances of a shared label.
 ///
 /// If no instances of the label name are found, the request will still be considered successful.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn remove_shared_labels(
     config: &TodoistConfig,
     name: String,
@@ -1,6 +1,13 @@
 //! The module containing the main Todoist API.
+pub mod attachments;
+pub mod bulk;
 pub mod comments;
+pub mod label_tools;
 pub mod labels;
 pub mod projects;
+pub mod reminders;
+pub mod reorder;
 pub mod sections;
 pub mod tasks;
+pub mod uploads;
+pub mod workspaces;
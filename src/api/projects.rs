@@ -1,19 +1,46 @@
 //! Todoist Projects API (<https://developer.todoist.com/rest/v2/?shell#projects>)
+//!
+//! Collaborators with roles, sharing and invitations go through the Sync API, and archiving
+//! has no API v2 endpoint, so those functions always use API v1, whatever the
+//! [ApiVersion] of the config. Project IDs from API v2 are translated into their API v1 IDs
+//! before being sent to API v1.
 
-use crate::internal::request::models::{CreateNewProjectArgs, UpdateProjectArgs};
+use futures_util::{Stream, TryStreamExt};
+use serde::Deserialize;
+
+use crate::internal::request::id_mappings::{get_v1_id, IdKind};
+use crate::internal::request::models::{
+    CreateNewProjectArgs, DeleteCollaboratorArgs, InvitationArgs, ShareProjectArgs,
+    UpdateProjectArgs,
+};
+use crate::internal::request::pagination::{
+    get_all_results, stream_results, stream_versioned_results,
+};
 use crate::internal::request::paths::create_path;
+use crate::internal::request::sync::{read_resources, send_sync_commands, SyncCommand};
 use crate::internal::request::{
     paths, send_todoist_delete_request, send_todoist_get_request, send_todoist_post_request,
-    TodoistAPIError,
+    send_todoist_versioned_post_request, TodoistAPIError,
 };
-use crate::model::collaborator::Collaborator;
+use crate::model::collaborator::{Collaborator, CollaboratorRole};
 use crate::model::color::Color;
 use crate::model::project::{Project, ProjectViewStyle};
-use crate::todoist_config::TodoistConfig;
+use crate::todoist_config::{ApiVersion, TodoistConfig};
 
 /// Gets all user [projects](Project)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_projects(config: &TodoistConfig) -> Result<Vec<Project>, TodoistAPIError> {
-    send_todoist_get_request(config, paths::PROJECTS.to_string()).await
+    get_all_results(config, paths::PROJECTS.to_string()).await
+}
+
+/// Gets all user [projects](Project) as a [Stream]
+///
+/// With API v1, the projects are requested page by page as the stream is consumed.
+/// API v2 returns every project in a single response, so the stream only saves collecting them.
+pub fn stream_all_projects(
+    config: &TodoistConfig,
+) -> impl Stream<Item = Result<Project, TodoistAPIError>> + '_ {
+    stream_results(config, paths::PROJECTS.to_string())
 }
 
 /// Creates a new [project](Project)
@@ -25,6 +52,7 @@ pub async fn get_all_projects(config: &TodoistConfig) -> Result<Vec<Project>, To
 /// * `color` - The [color](Color) of the project icon
 /// * `is_favorite` - Whether the project is a favorite
 /// * `view_style` - The way the project is displayed within the Todoist clients
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn create_new_project(
     config: &TodoistConfig,
     name: String,
@@ -49,6 +77,7 @@ pub async fn create_new_project(
 }
 
 /// Gets a [Project] by its ID
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_project(
     config: &TodoistConfig,
     project_id: String,
@@ -64,6 +93,7 @@ pub async fn get_project(
 /// * `color` - The [color](Color) of the project icon
 /// * `is_favorite` - Whether the project is a favorite
 /// * `view_style` - The way the project is displayed within the Todoist clients
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn update_project(
     config: &TodoistConfig,
     project_id: String,
@@ -87,6 +117,7 @@ pub async fn update_project(
 }
 
 /// Deletes a project
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn delete_project(
     config: &TodoistConfig,
     project_id: String,
@@ -95,13 +126,364 @@ pub async fn delete_project(
 }
 
 /// Gets all of the collaborators of a shared project
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_collaborators(
     config: &TodoistConfig,
     project_id: String,
 ) -> Result<Vec<Collaborator>, TodoistAPIError> {
-    send_todoist_get_request(
+    get_all_results(
         config,
         create_path(&[paths::PROJECTS, &project_id, paths::COLLABORATORS]),
     )
     .await
 }
+
+#[derive(Deserialize)]
+struct CollaboratorsResource {
+    collaborators: Vec<Collaborator>,
+    collaborator_states: Vec<CollaboratorState>,
+}
+
+#[derive(Deserialize)]
+struct CollaboratorState {
+    project_id: String,
+    user_id: String,
+    // `active`, `invited` or `deleted`
+    state: String,
+    #[serde(default)]
+    is_deleted: bool,
+    #[serde(default)]
+    role: Option<CollaboratorRole>,
+}
+
+/// Gets all of the collaborators of a project, including their [role](CollaboratorRole)
+/// for projects in a workspace and the collaborators that were invited but did not accept yet
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_collaborators_with_roles(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Vec<Collaborator>, TodoistAPIError> {
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    let resource: CollaboratorsResource =
+        read_resources(config, &["collaborators", "collaborator_states"]).await?;
+    Ok(resource
+        .collaborator_states
+        .into_iter()
+        .filter(|state| {
+            state.project_id == project_id && !state.is_deleted && state.state != "deleted"
+        })
+        .filter_map(|state| {
+            resource
+                .collaborators
+                .iter()
+                .find(|collaborator| collaborator.id == state.user_id)
+                .map(|collaborator| Collaborator {
+                    role: state.role,
+                    ..collaborator.clone()
+                })
+        })
+        .collect())
+}
+
+/// Shares a project with the user of `email`, who is invited to collaborate on the project
+///
+/// `role` is only used for projects in a workspace ([None] uses the default role of the workspace).
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn share_project(
+    config: &TodoistConfig,
+    project_id: String,
+    email: String,
+    role: Option<CollaboratorRole>,
+) -> Result<(), TodoistAPIError> {
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    send_sync_commands(
+        config,
+        &[SyncCommand::new(
+            "share_project",
+            ShareProjectArgs {
+                project_id,
+                email,
+                role,
+            },
+        )],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Removes the collaborator with the given `email` from a shared project
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn delete_collaborator(
+    config: &TodoistConfig,
+    project_id: String,
+    email: String,
+) -> Result<(), TodoistAPIError> {
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    send_sync_commands(
+        config,
+        &[SyncCommand::new(
+            "delete_collaborator",
+            DeleteCollaboratorArgs { project_id, email },
+        )],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Accepts an invitation to collaborate on a shared project
+///
+/// The ID and secret of the invitation are part of the notification of the invitation.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn accept_invitation(
+    config: &TodoistConfig,
+    invitation_id: String,
+    invitation_secret: String,
+) -> Result<(), TodoistAPIError> {
+    send_invitation_command(
+        config,
+        "accept_invitation",
+        invitation_id,
+        invitation_secret,
+    )
+    .await
+}
+
+/// Rejects an invitation to collaborate on a shared project
+///
+/// The ID and secret of the invitation are part of the notification of the invitation.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn reject_invitation(
+    config: &TodoistConfig,
+    invitation_id: String,
+    invitation_secret: String,
+) -> Result<(), TodoistAPIError> {
+    send_invitation_command(
+        config,
+        "reject_invitation",
+        invitation_id,
+        invitation_secret,
+    )
+    .await
+}
+
+async fn send_invitation_command(
+    config: &TodoistConfig,
+    command_type: &'static str,
+    invitation_id: String,
+    invitation_secret: String,
+) -> Result<(), TodoistAPIError> {
+    send_sync_commands(
+        config,
+        &[SyncCommand::new(
+            command_type,
+            InvitationArgs {
+                invitation_id,
+                invitation_secret,
+            },
+        )],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Archives a [Project] and its children, returning the archived project
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn archive_project(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Project, TodoistAPIError> {
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    send_todoist_versioned_post_request::<(), _>(
+        config,
+        ApiVersion::V1,
+        create_path(&[paths::PROJECTS, &project_id, paths::ARCHIVE]),
+        None,
+        true,
+    )
+    .await
+}
+
+/// Unarchives a [Project], returning the unarchived project
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn unarchive_project(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Project, TodoistAPIError> {
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    send_todoist_versioned_post_request::<(), _>(
+        config,
+        ApiVersion::V1,
+        create_path(&[paths::PROJECTS, &project_id, paths::UNARCHIVE]),
+        None,
+        true,
+    )
+    .await
+}
+
+/// Gets all archived [projects](Project)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_archived_projects(
+    config: &TodoistConfig,
+) -> Result<Vec<Project>, TodoistAPIError> {
+    stream_archived_projects(config).try_collect().await
+}
+
+/// Gets all archived [projects](Project) as a [Stream]
+///
+/// The projects are requested page by page as the stream is consumed,
+/// and each page is read in full before its projects are yielded.
+pub fn stream_archived_projects(
+    config: &TodoistConfig,
+) -> impl Stream<Item = Result<Project, TodoistAPIError>> + '_ {
+    stream_versioned_results(
+        config,
+        ApiVersion::V1,
+        create_path(&[paths::PROJECTS, paths::ARCHIVED]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    struct ArchivedProjects;
+
+    #[async_trait]
+    impl Middleware for ArchivedProjects {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let (id, next_cursor) = match request.url.as_str() {
+                "https://api.todoist.com/api/v1/id_mappings/projects/2203306141" => {
+                    return Ok(Some(create_response(
+                        r#"[{"old_id": "2203306141", "new_id": "3"}]"#.into(),
+                    )))
+                }
+                "https://api.todoist.com/api/v1/projects/archived" => ("1", r#""a""#),
+                "https://api.todoist.com/api/v1/projects/archived?cursor=a" => ("2", "null"),
+                "https://api.todoist.com/api/v1/projects/3/unarchive" => {
+                    return Ok(Some(create_response(
+                        r#"{"id": "3", "name": "Client", "color": "grey", "parent_id": null,
+                            "child_order": 1, "view_style": "board", "is_archived": false}"#
+                            .into(),
+                    )))
+                }
+                url => panic!("unexpected URL {}", url),
+            };
+            Ok(Some(create_response(format!(
+                r#"{{"results": [{{"id": "{}", "name": "Client", "color": "grey", "parent_id": null,
+                    "child_order": 1, "view_style": "list", "is_archived": true}}],
+                    "next_cursor": {}}}"#,
+                id, next_cursor
+            ))))
+        }
+    }
+
+    fn create_response(body: String) -> TodoistResponse {
+        TodoistResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: body.into_bytes(),
+        }
+    }
+
+    #[tokio::test]
+    async fn archiving_always_uses_v1() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(ArchivedProjects);
+        let projects = get_archived_projects(&config).await.unwrap();
+        assert_eq!(projects.len(), 2);
+        assert!(projects.iter().all(|project| project.is_archived));
+        let project = unarchive_project(&config, "2203306141".into())
+            .await
+            .unwrap();
+        assert!(!project.is_archived);
+        assert_eq!(project.url, "https://app.todoist.com/app/project/3");
+    }
+
+    /// Maps the API v2 ID `2203306141` to the API v1 ID `10`, and answers reads of collaborators
+    struct CollaboratorsEndpoint;
+
+    #[async_trait]
+    impl Middleware for CollaboratorsEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            if let Some(id) = request
+                .url
+                .strip_prefix("https://api.todoist.com/api/v1/id_mappings/projects/")
+            {
+                let mappings = match id {
+                    "2203306141" => r#"[{"old_id": "2203306141", "new_id": "10"}]"#,
+                    _ => "[]",
+                };
+                return Ok(Some(create_response(mappings.into())));
+            }
+            assert_eq!(request.url, "https://api.todoist.com/api/v1/sync");
+            Ok(Some(create_response(
+                r#"{"sync_token": "a",
+                    "collaborators": [
+                        {"id": "1", "email": "bob@example.com", "full_name": "Bob",
+                         "timezone": "Europe/Lisbon", "image_id": "d160009dfd52b991030d55227003450f"},
+                        {"id": "2", "email": "alice@example.com", "full_name": "Alice",
+                         "timezone": "Europe/Lisbon", "image_id": null}
+                    ],
+                    "collaborator_states": [
+                        {"project_id": "10", "user_id": "1", "state": "active",
+                         "is_deleted": false, "role": "ADMIN"},
+                        {"project_id": "10", "user_id": "2", "state": "deleted",
+                         "is_deleted": false, "role": "READ_ONLY"},
+                        {"project_id": "11", "user_id": "2", "state": "invited",
+                         "is_deleted": false},
+                        {"project_id": "12", "user_id": "1", "state": "active",
+                         "is_deleted": false, "role": "GUEST"}
+                    ]}"#
+                .into(),
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn collaborators_carry_roles() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(CollaboratorsEndpoint);
+        let collaborators = get_collaborators_with_roles(&config, "2203306141".into())
+            .await
+            .unwrap();
+        assert_eq!(collaborators.len(), 1);
+        assert_eq!(collaborators[0].name, "Bob");
+        assert_eq!(collaborators[0].extra["timezone"], "Europe/Lisbon");
+        assert_eq!(collaborators[0].role, Some(CollaboratorRole::Admin));
+        assert_eq!(
+            collaborators[0]
+                .get_avatar_url(crate::model::collaborator::AvatarSize::Big)
+                .unwrap(),
+            "https://dcff1xvirvpfp.cloudfront.net/d160009dfd52b991030d55227003450f_big.jpg"
+        );
+        let collaborators = get_collaborators_with_roles(&config, "11".into())
+            .await
+            .unwrap();
+        assert_eq!(collaborators[0].name, "Alice");
+        assert_eq!(collaborators[0].role, None);
+        assert_eq!(
+            collaborators[0].get_avatar_url(crate::model::collaborator::AvatarSize::Small),
+            None
+        );
+        let collaborators = get_collaborators_with_roles(&config, "12".into())
+            .await
+            .unwrap();
+        assert_eq!(
+            collaborators[0].role,
+            Some(CollaboratorRole::Unknown("GUEST".into()))
+        );
+    }
+}
@@ -0,0 +1,343 @@
+//! Todoist Reminders API (<https://developer.todoist.com/api/v1/#tag/Sync/Reminders>)
+//!
+//! Reminders only exist in the Sync API, which is part of API v1, so the
+//! [ApiVersion](crate::todoist_config::ApiVersion) of the config is not used.
+//! Task IDs from API v2 are translated into their API v1 IDs, which are the IDs used by reminders.
+
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use serde::Deserialize;
+
+use crate::internal::request::id_mappings::{get_v1_id, IdKind};
+use crate::internal::request::models::{
+    CreateNewReminderArgs, DeleteReminderArgs, UpdateReminderArgs,
+};
+use crate::internal::request::sync::{
+    read_resources, send_sync_commands, send_sync_commands_and_read_resources, SyncCommand,
+};
+use crate::internal::request::InvalidRequestError;
+use crate::model::reminder::{Reminder, ReminderType};
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
+#[derive(Deserialize)]
+struct RemindersResource {
+    reminders: Vec<SyncReminder>,
+}
+
+impl RemindersResource {
+    fn into_reminders(self) -> impl Iterator<Item = Reminder> {
+        self.reminders
+            .into_iter()
+            .filter(|reminder| !reminder.is_deleted)
+            .map(|reminder| reminder.reminder)
+    }
+}
+
+#[derive(Deserialize)]
+struct SyncReminder {
+    #[serde(flatten)]
+    reminder: Reminder,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+/// Get all [reminders](Reminder)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_all_reminders(config: &TodoistConfig) -> Result<Vec<Reminder>, TodoistAPIError> {
+    let resource: RemindersResource = read_resources(config, &["reminders"]).await?;
+    Ok(resource.into_reminders().collect())
+}
+
+/// Get all [reminders](Reminder) of a [Task](crate::model::task::Task)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_task_reminders(
+    config: &TodoistConfig,
+    task_id: String,
+) -> Result<Vec<Reminder>, TodoistAPIError> {
+    let task_id = get_v1_id(config, IdKind::Task, task_id).await?;
+    let mut reminders = get_all_reminders(config).await?;
+    reminders.retain(|reminder| reminder.task_id == task_id);
+    Ok(reminders)
+}
+
+/// Get a single [Reminder]
+///
+/// The Sync API cannot read a single reminder, so every reminder is read to find this one.
+/// If the reminder does not exist, an invalid request error with the status code `404` is returned.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_reminder(
+    config: &TodoistConfig,
+    reminder_id: String,
+) -> Result<Reminder, TodoistAPIError> {
+    let resource: RemindersResource = read_resources(config, &["reminders"]).await?;
+    find_reminder(resource, &reminder_id)
+}
+
+/// Create a new [Reminder] for a [Task](crate::model::task::Task)
+///
+/// `notify_uid` is the ID of the user to notify, or [None] to notify the current user.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn create_new_reminder(
+    config: &TodoistConfig,
+    task_id: String,
+    reminder_type: ReminderType,
+    notify_uid: Option<String>,
+) -> Result<Reminder, TodoistAPIError> {
+    let task_id = get_v1_id(config, IdKind::Task, task_id).await?;
+    let command = SyncCommand::new_with_temp_id(
+        "reminder_add",
+        CreateNewReminderArgs {
+            item_id: task_id,
+            reminder_type,
+            notify_uid,
+        },
+    );
+    let temp_id = command.temp_id.clone().unwrap_or_default();
+    let mut temp_id_mapping = send_sync_commands(config, std::slice::from_ref(&command)).await?;
+    let args = command.args;
+    Ok(Reminder {
+        id: temp_id_mapping.remove(&temp_id).unwrap_or(temp_id),
+        task_id: args.item_id,
+        notify_uid: args.notify_uid,
+        reminder_type: args.reminder_type,
+        extra: HashMap::new(),
+    })
+}
+
+/// Update a [Reminder]
+///
+/// The type of the reminder is only changed if `reminder_type` is [Some].
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn update_reminder(
+    config: &TodoistConfig,
+    reminder_id: String,
+    reminder_type: Option<ReminderType>,
+    notify_uid: Option<String>,
+) -> Result<Reminder, TodoistAPIError> {
+    // The updated reminder is read in the same request, as the Sync API cannot read a single reminder
+    let (_, resource) = send_sync_commands_and_read_resources(
+        config,
+        &[SyncCommand::new(
+            "reminder_update",
+            UpdateReminderArgs {
+                id: reminder_id.clone(),
+                reminder_type,
+                notify_uid,
+            },
+        )],
+        &["reminders"],
+    )
+    .await?;
+    find_reminder(resource, &reminder_id)
+}
+
+/// Delete a [Reminder]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn delete_reminder(
+    config: &TodoistConfig,
+    reminder_id: String,
+) -> Result<(), TodoistAPIError> {
+    send_sync_commands(
+        config,
+        &[SyncCommand::new(
+            "reminder_delete",
+            DeleteReminderArgs { id: reminder_id },
+        )],
+    )
+    .await?;
+    Ok(())
+}
+
+fn find_reminder(
+    resource: RemindersResource,
+    reminder_id: &str,
+) -> Result<Reminder, TodoistAPIError> {
+    resource
+        .into_reminders()
+        .find(|reminder| reminder.id == reminder_id)
+        .ok_or_else(|| {
+            InvalidRequestError {
+                status_code: NonZeroU16::new(404).unwrap(),
+            }
+            .into()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+    use crate::model::reminder::LocationTrigger;
+    use crate::todoist_config::ApiVersion;
+
+    /// Maps the API v2 ID `2995104339` to the API v1 ID `10`, answers reads with the stored reminders,
+    /// and applies `reminder_add` and `reminder_update` commands to them
+    struct RemindersEndpoint {
+        reminders: Mutex<Vec<serde_json::Value>>,
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl Middleware for RemindersEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let mut requests = self.requests.lock().unwrap();
+            if let Some(ids) = request
+                .url
+                .strip_prefix("https://api.todoist.com/api/v1/id_mappings/tasks/")
+            {
+                requests.push("id_mappings".into());
+                let new_id = if ids == "2995104339" { "10" } else { ids };
+                let mappings = serde_json::json!([{"old_id": ids, "new_id": new_id}]);
+                return Ok(Some(TodoistResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: serde_json::to_vec(&mappings).unwrap(),
+                }));
+            }
+            let body: serde_json::Value =
+                serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+            let mut reminders = self.reminders.lock().unwrap();
+            let mut response = serde_json::json!({"sync_status": {}, "temp_id_mapping": {}});
+            for command in body["commands"].as_array().into_iter().flatten() {
+                requests.push(command["type"].as_str().unwrap().into());
+                let args = &command["args"];
+                match command["type"].as_str().unwrap() {
+                    "reminder_add" => {
+                        let mut reminder = args.clone();
+                        reminder["id"] = "3".into();
+                        reminders.push(reminder);
+                        response["temp_id_mapping"][command["temp_id"].as_str().unwrap()] =
+                            "3".into();
+                    }
+                    "reminder_update" => {
+                        let reminder = reminders
+                            .iter_mut()
+                            .find(|reminder| reminder["id"] == args["id"])
+                            .unwrap();
+                        for (key, value) in args.as_object().unwrap() {
+                            reminder[key] = value.clone();
+                        }
+                    }
+                    command_type => panic!("unexpected command {}", command_type),
+                }
+                response["sync_status"][command["uuid"].as_str().unwrap()] = "ok".into();
+            }
+            if let Some(resource_types) = body.get("resource_types") {
+                requests.push("read".into());
+                assert_eq!(*resource_types, serde_json::json!(["reminders"]));
+                response["reminders"] = reminders.clone().into();
+                response["sync_token"] = "a".into();
+            }
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&response).unwrap(),
+            }))
+        }
+    }
+
+    fn create_config() -> (TodoistConfig, Arc<RemindersEndpoint>) {
+        let reminders = serde_json::json!([
+            {"id": "1", "notify_uid": null, "item_id": "10", "type": "relative",
+             "minute_offset": 30, "is_deleted": false},
+            {"id": "2", "notify_uid": null, "item_id": "10", "type": "relative",
+             "minute_offset": 60, "is_deleted": true},
+        ]);
+        let endpoint = Arc::new(RemindersEndpoint {
+            reminders: Mutex::new(serde_json::from_value(reminders).unwrap()),
+            requests: Mutex::new(Vec::new()),
+        });
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(endpoint.clone());
+        (config, endpoint)
+    }
+
+    #[tokio::test]
+    async fn deleted_reminders_are_skipped() {
+        let (config, _) = create_config();
+        let reminders = get_task_reminders(&config, "2995104339".into())
+            .await
+            .unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(reminders[0].id, "1");
+        match get_reminder(&config, "2".into()).await.unwrap_err() {
+            TodoistAPIError::InvalidRequest(error) => assert_eq!(error.status_code.get(), 404),
+            error => panic!("unexpected error {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn v1_task_ids_are_not_mapped() {
+        let (config, endpoint) = create_config();
+        let config = config.with_api_version(ApiVersion::V1);
+        let reminders = get_task_reminders(&config, "10".into()).await.unwrap();
+        assert_eq!(reminders.len(), 1);
+        assert_eq!(*endpoint.requests.lock().unwrap(), ["read"]);
+    }
+
+    #[tokio::test]
+    async fn created_reminders_are_not_read_back() {
+        let (config, endpoint) = create_config();
+        let reminder = create_new_reminder(
+            &config,
+            "2995104339".into(),
+            ReminderType::Location {
+                name: "Aliados".into(),
+                latitude: "41.148581".into(),
+                longitude: "-8.610945000000015".into(),
+                trigger: LocationTrigger::OnLeave,
+                radius: 100,
+            },
+            Some("2671355".into()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(reminder.id, "3");
+        assert_eq!(reminder.task_id, "10");
+        assert_eq!(reminder.notify_uid.as_deref(), Some("2671355"));
+        assert!(matches!(
+            reminder.reminder_type,
+            ReminderType::Location {
+                trigger: LocationTrigger::OnLeave,
+                ..
+            }
+        ));
+        assert_eq!(
+            *endpoint.requests.lock().unwrap(),
+            ["id_mappings", "reminder_add"]
+        );
+    }
+
+    #[tokio::test]
+    async fn updated_reminders_are_read_in_the_same_request() {
+        let (config, endpoint) = create_config();
+        let reminder = update_reminder(
+            &config,
+            "1".into(),
+            Some(ReminderType::Relative { minute_offset: 15 }),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            reminder.reminder_type,
+            ReminderType::Relative { minute_offset: 15 }
+        ));
+        assert_eq!(
+            *endpoint.requests.lock().unwrap(),
+            ["reminder_update", "read"]
+        );
+    }
+}
@@ -0,0 +1,424 @@
+//! Reordering of [tasks](crate::model::task::Task), [projects](crate::model::project::Project),
+//! [sections](crate::model::section::Section) and [labels](crate::model::label::PersonalLabel)
+//!
+//! The order of a resource is its position among the resources with the same parent,
+//! starting at `1`. The new orders are sent as Sync API commands, so requests go to
+//! API v1 even if the config uses another [ApiVersion]. IDs from API v2 are translated
+//! into their API v1 IDs before being sent.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use crate::internal::request::id_mappings::{get_v1_id, IdKind};
+use crate::internal::request::models::{
+    ChildOrderArgs, ReorderProjectsArgs, ReorderSectionsArgs, ReorderTasksArgs, SectionOrderArgs,
+    UpdateLabelOrdersArgs,
+};
+use crate::internal::request::sync::{read_resources, send_sync_commands, SyncCommand};
+use crate::todoist_config::{ApiVersion, TodoistConfig};
+use crate::TodoistAPIError;
+
+/// The kind of resources to reorder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReorderTarget {
+    /// [Tasks](crate::model::task::Task) with the same parent task (or in the same project or section)
+    Tasks,
+    /// [Projects](crate::model::project::Project) with the same parent project
+    Projects,
+    /// [Sections](crate::model::section::Section) of the same project
+    Sections,
+    /// Personal [labels](crate::model::label::PersonalLabel)
+    Labels,
+}
+
+/// A new order for a single resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderChange {
+    /// The ID of the resource
+    pub id: String,
+    /// The new position of the resource among the resources with the same parent (starting at `1`)
+    pub order: u32,
+}
+
+/// Computes the order changes that give the resources of `after` the orders `1` to `n`,
+/// from the current `(id, order)` pairs of the resources in `before`
+///
+/// The current orders do not have to start at `1` or be consecutive (ex: after a resource was deleted),
+/// as the resources are renumbered. Only the resources whose order changed are returned.
+/// Resources of `after` that are not in `before` are always returned,
+/// and resources of `before` that are not in `after` are ignored.
+pub fn compute_order_changes(before: &[(String, u32)], after: &[String]) -> Vec<OrderChange> {
+    let current_orders: HashMap<&str, u32> = before
+        .iter()
+        .map(|(id, order)| (id.as_str(), *order))
+        .collect();
+    after
+        .iter()
+        .zip(1..)
+        .filter(|(id, order)| current_orders.get(id.as_str()) != Some(order))
+        .map(|(id, order)| OrderChange {
+            id: id.clone(),
+            order,
+        })
+        .collect()
+}
+
+/// Reorders resources with the same parent, so that they appear in the order of `ids`
+///
+/// The current orders of the resources are read first, so that only the resources
+/// whose order changes are sent.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn reorder(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    ids: Vec<String>,
+) -> Result<(), TodoistAPIError> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+    let current_orders = get_current_orders(config, target, &ids).await?;
+    apply_order_changes(config, target, compute_order_changes(&current_orders, &ids)).await
+}
+
+/// Get the current `(id, order)` pairs of the resources of `target`
+async fn get_current_orders(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    ids: &[String],
+) -> Result<Vec<(String, u32)>, TodoistAPIError> {
+    Ok(match target {
+        ReorderTarget::Tasks => {
+            crate::tasks::get_active_tasks(config, None, None, None, None, None, Some(ids))
+                .await?
+                .into_iter()
+                .map(|task| (task.id, task.order))
+                .collect()
+        }
+        ReorderTarget::Projects => crate::projects::get_all_projects(config)
+            .await?
+            .into_iter()
+            .map(|project| (project.id, project.order))
+            .collect(),
+        ReorderTarget::Sections => crate::sections::get_all_sections(config, None)
+            .await?
+            .into_iter()
+            .map(|section| (section.id, section.order))
+            .collect(),
+        ReorderTarget::Labels => crate::labels::get_all_personal_labels(config)
+            .await?
+            .into_iter()
+            .map(|label| (label.id, label.order))
+            .collect(),
+    })
+}
+
+/// Applies order changes (such as the ones from [compute_order_changes]) in a single Sync API request
+///
+/// The IDs are translated into API v1 IDs first, which takes one more request per ID with API v2
+/// (or two requests in total for labels).
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn apply_order_changes(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    changes: Vec<OrderChange>,
+) -> Result<(), TodoistAPIError> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+    let changes = get_v1_changes(config, target, changes).await?;
+    let child_orders = || {
+        changes
+            .iter()
+            .map(|change| ChildOrderArgs {
+                id: change.id.clone(),
+                child_order: change.order,
+            })
+            .collect()
+    };
+    match target {
+        ReorderTarget::Tasks => {
+            send_command(
+                config,
+                "item_reorder",
+                ReorderTasksArgs {
+                    items: child_orders(),
+                },
+            )
+            .await
+        }
+        ReorderTarget::Projects => {
+            send_command(
+                config,
+                "project_reorder",
+                ReorderProjectsArgs {
+                    projects: child_orders(),
+                },
+            )
+            .await
+        }
+        ReorderTarget::Sections => {
+            send_command(
+                config,
+                "section_reorder",
+                ReorderSectionsArgs {
+                    sections: changes
+                        .iter()
+                        .map(|change| SectionOrderArgs {
+                            id: change.id.clone(),
+                            section_order: change.order,
+                        })
+                        .collect(),
+                },
+            )
+            .await
+        }
+        ReorderTarget::Labels => {
+            send_command(
+                config,
+                "label_update_orders",
+                UpdateLabelOrdersArgs {
+                    id_order_mapping: changes
+                        .iter()
+                        .map(|change| (change.id.clone(), change.order))
+                        .collect::<BTreeMap<_, _>>(),
+                },
+            )
+            .await
+        }
+    }
+}
+
+/// Translates the IDs of `changes` into API v1 IDs, which are the IDs used by the Sync API
+async fn get_v1_changes(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    changes: Vec<OrderChange>,
+) -> Result<Vec<OrderChange>, TodoistAPIError> {
+    let kind = match target {
+        ReorderTarget::Tasks => IdKind::Task,
+        ReorderTarget::Projects => IdKind::Project,
+        ReorderTarget::Sections => IdKind::Section,
+        ReorderTarget::Labels => return get_v1_label_changes(config, changes).await,
+    };
+    let mut v1_changes = Vec::with_capacity(changes.len());
+    for change in changes {
+        v1_changes.push(OrderChange {
+            id: get_v1_id(config, kind, change.id).await?,
+            order: change.order,
+        });
+    }
+    Ok(v1_changes)
+}
+
+#[derive(Deserialize)]
+struct LabelsResource {
+    labels: Vec<SyncLabel>,
+}
+
+#[derive(Deserialize)]
+struct SyncLabel {
+    id: String,
+    name: String,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+/// Translates the IDs of `changes` into API v1 IDs using the names of the labels,
+/// as the ID mappings endpoint does not support labels
+async fn get_v1_label_changes(
+    config: &TodoistConfig,
+    changes: Vec<OrderChange>,
+) -> Result<Vec<OrderChange>, TodoistAPIError> {
+    if config.get_api_version() == ApiVersion::V1 {
+        return Ok(changes);
+    }
+    let names: HashMap<String, String> = crate::labels::get_all_personal_labels(config)
+        .await?
+        .into_iter()
+        .map(|label| (label.id, label.name))
+        .collect();
+    let resource: LabelsResource = read_resources(config, &["labels"]).await?;
+    let v1_ids: HashMap<String, String> = resource
+        .labels
+        .into_iter()
+        .filter(|label| !label.is_deleted)
+        .map(|label| (label.name, label.id))
+        .collect();
+    Ok(changes
+        .into_iter()
+        .map(|change| OrderChange {
+            id: names
+                .get(&change.id)
+                .and_then(|name| v1_ids.get(name))
+                .cloned()
+                .unwrap_or(change.id),
+            order: change.order,
+        })
+        .collect())
+}
+
+async fn send_command<A: serde::Serialize>(
+    config: &TodoistConfig,
+    command_type: &'static str,
+    args: A,
+) -> Result<(), TodoistAPIError> {
+    send_sync_commands(config, &[SyncCommand::new(command_type, args)]).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn change(id: &str, order: u32) -> OrderChange {
+        OrderChange {
+            id: id.into(),
+            order,
+        }
+    }
+
+    fn orders(orders: &[(&str, u32)]) -> Vec<(String, u32)> {
+        orders
+            .iter()
+            .map(|(id, order)| (id.to_string(), *order))
+            .collect()
+    }
+
+    #[test]
+    fn only_moved_resources_change() {
+        let before = orders(&[("1", 1), ("2", 2), ("3", 3), ("4", 4)]);
+        let before_ids = ids(&["1", "2", "3", "4"]);
+        assert_eq!(compute_order_changes(&before, &before_ids), vec![]);
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["2", "1", "3", "4"])),
+            vec![change("2", 1), change("1", 2)]
+        );
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["1", "2", "4"])),
+            vec![change("4", 3)]
+        );
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["1", "2", "3", "4", "5"])),
+            vec![change("5", 5)]
+        );
+    }
+
+    #[test]
+    fn gapped_orders_are_renumbered() {
+        let before = orders(&[("1", 1), ("2", 3), ("3", 4), ("4", 8)]);
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["1", "2", "3", "4"])),
+            vec![change("2", 2), change("3", 3), change("4", 4)]
+        );
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["1", "4", "2", "3"])),
+            vec![change("4", 2)]
+        );
+        let before = orders(&[("1", 0), ("2", 1)]);
+        assert_eq!(
+            compute_order_changes(&before, &ids(&["1", "2"])),
+            vec![change("1", 1), change("2", 2)]
+        );
+    }
+
+    /// Maps every API v2 task ID `id` to the API v1 ID `v1-id`, answers reads of labels
+    /// and records the commands that are sent
+    struct SyncEndpoint {
+        commands: Mutex<Vec<serde_json::Value>>,
+    }
+
+    #[async_trait]
+    impl Middleware for SyncEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let body = match request.url.as_str() {
+                "https://api.todoist.com/rest/v2/labels" => serde_json::json!([
+                    {"id": "7", "name": "Food", "color": "red", "order": 1, "is_favorite": false},
+                    {"id": "3", "name": "Shopping", "color": "blue", "order": 3,
+                     "is_favorite": false},
+                ]),
+                "https://api.todoist.com/api/v1/sync" => {
+                    let body: serde_json::Value =
+                        serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+                    match body.get("commands") {
+                        Some(commands) => {
+                            let command = commands[0].clone();
+                            let uuid = command["uuid"].as_str().unwrap().to_string();
+                            self.commands.lock().unwrap().push(command);
+                            serde_json::json!({"sync_status": {uuid: "ok"}})
+                        }
+                        None => serde_json::json!({"sync_token": "a", "labels": [
+                            {"id": "2156154810", "name": "Shopping", "is_deleted": true},
+                            {"id": "6X7", "name": "Food", "is_deleted": false},
+                            {"id": "6X8", "name": "Shopping", "is_deleted": false},
+                        ]}),
+                    }
+                }
+                url => {
+                    let id = url
+                        .strip_prefix("https://api.todoist.com/api/v1/id_mappings/tasks/")
+                        .unwrap_or_else(|| panic!("unexpected URL {}", url));
+                    serde_json::json!([{"old_id": id, "new_id": format!("v1-{}", id)}])
+                }
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&body).unwrap(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn commands_match_the_target() {
+        let endpoint = std::sync::Arc::new(SyncEndpoint {
+            commands: Mutex::new(Vec::new()),
+        });
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(endpoint.clone());
+        apply_order_changes(
+            &config,
+            ReorderTarget::Tasks,
+            vec![change("7", 1), change("3", 2)],
+        )
+        .await
+        .unwrap();
+        // The label "7" is already first, so only the label "3" is moved
+        reorder(&config, ReorderTarget::Labels, ids(&["7", "3"]))
+            .await
+            .unwrap();
+        apply_order_changes(&config, ReorderTarget::Projects, vec![])
+            .await
+            .unwrap();
+        let commands = endpoint.commands.lock().unwrap();
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0]["type"], "item_reorder");
+        assert_eq!(
+            commands[0]["args"],
+            serde_json::json!({"items": [
+                {"id": "v1-7", "child_order": 1},
+                {"id": "v1-3", "child_order": 2},
+            ]})
+        );
+        assert_eq!(commands[1]["type"], "label_update_orders");
+        assert_eq!(
+            commands[1]["args"],
+            serde_json::json!({"id_order_mapping": {"6X8": 2}})
+        );
+    }
+}
@@ -1,20 +1,33 @@
 //! Todoist Sections API (<https://developer.todoist.com/rest/v2/?shell#sections>)
+//!
+//! Archiving, unarchiving, moving and reordering sections use the Sync API, so only those
+//! functions always use API v1. The other functions use the
+//! [ApiVersion](crate::todoist_config::ApiVersion) of the config.
+//! Section and project IDs from API v2 are translated into their API v1 IDs
+//! before being sent to the Sync API.
 
-use crate::internal::request::models::{CreateNewSectionArgs, UpdateSectionArgs};
+use crate::internal::request::id_mappings::{get_v1_id, IdKind};
+use crate::internal::request::models::{
+    CreateNewSectionArgs, MoveSectionArgs, SectionIdArgs, UpdateSectionArgs,
+};
+use crate::internal::request::pagination::get_all_results;
 use crate::internal::request::paths::create_path;
+use crate::internal::request::sync::{send_sync_commands, SyncCommand};
 use crate::internal::request::{
     paths, send_todoist_delete_request, send_todoist_get_request, send_todoist_post_request,
 };
 use crate::model::section::Section;
+use crate::reorder::ReorderTarget;
 use crate::todoist_config::TodoistConfig;
 use crate::TodoistAPIError;
 
 /// Get all [sections](Section), optionally filtering the returned sections with a project ID
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_all_sections(
     config: &TodoistConfig,
     project_id: Option<String>,
 ) -> Result<Vec<Section>, TodoistAPIError> {
-    send_todoist_get_request(
+    get_all_results(
         config,
         match project_id {
             Some(id) => create_path(&[paths::SECTIONS, paths::PARAM_PROJECT_ID, &id]),
@@ -25,6 +38,7 @@ pub async fn get_all_sections(
 }
 
 /// Create a new [Section]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn create_new_section(
     config: &TodoistConfig,
     project_id: String,
@@ -45,6 +59,7 @@ pub async fn create_new_section(
 }
 
 /// Get a single [Section]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_section(
     config: &TodoistConfig,
     section_id: String,
@@ -53,6 +68,10 @@ pub async fn get_section(
 }
 
 /// Update a [Section]
+///
+/// Only the name can be changed: the sections endpoints do not accept `is_collapsed`,
+/// which can only be changed through the Sync API.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn update_section(
     config: &TodoistConfig,
     section_id: String,
@@ -68,6 +87,7 @@ pub async fn update_section(
 }
 
 /// Delete a [Section]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn delete_section(
     config: &TodoistConfig,
     section_id: String,
@@ -75,6 +95,194 @@ pub async fn delete_section(
     send_todoist_delete_request(config, get_section_path(section_id)).await
 }
 
+/// Archive a [Section] and its tasks
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn archive_section(
+    config: &TodoistConfig,
+    section_id: String,
+) -> Result<(), TodoistAPIError> {
+    let section_id = get_v1_id(config, IdKind::Section, section_id).await?;
+    send_section_command(config, "section_archive", SectionIdArgs { id: section_id }).await
+}
+
+/// Unarchive a [Section]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn unarchive_section(
+    config: &TodoistConfig,
+    section_id: String,
+) -> Result<(), TodoistAPIError> {
+    let section_id = get_v1_id(config, IdKind::Section, section_id).await?;
+    send_section_command(
+        config,
+        "section_unarchive",
+        SectionIdArgs { id: section_id },
+    )
+    .await
+}
+
+/// Move a [Section] and its tasks to another [Project](crate::model::project::Project)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn move_section(
+    config: &TodoistConfig,
+    section_id: String,
+    project_id: String,
+) -> Result<(), TodoistAPIError> {
+    let section_id = get_v1_id(config, IdKind::Section, section_id).await?;
+    let project_id = get_v1_id(config, IdKind::Project, project_id).await?;
+    send_section_command(
+        config,
+        "section_move",
+        MoveSectionArgs {
+            id: section_id,
+            project_id,
+        },
+    )
+    .await
+}
+
+/// Reorder the [sections](Section) of a project, so that they appear in the order of `section_ids`
+///
+/// All of the IDs must belong to sections of the same project.
+///
+/// This function is a shortcut for [`todoist_rest_api::reorder::reorder(config, ReorderTarget::Sections, section_ids)`](crate::reorder::reorder)
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn reorder_sections(
+    config: &TodoistConfig,
+    section_ids: Vec<String>,
+) -> Result<(), TodoistAPIError> {
+    crate::reorder::reorder(config, ReorderTarget::Sections, section_ids).await
+}
+
+async fn send_section_command<A: serde::Serialize>(
+    config: &TodoistConfig,
+    command_type: &'static str,
+    args: A,
+) -> Result<(), TodoistAPIError> {
+    send_sync_commands(config, &[SyncCommand::new(command_type, args)]).await?;
+    Ok(())
+}
+
 fn get_section_path(section_id: String) -> String {
     create_path(&[paths::SECTIONS, &section_id])
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    /// Maps every API v2 ID `id` to the API v1 ID `v1-id`, answers reads of sections
+    /// and records the commands that are sent
+    struct SyncEndpoint {
+        commands: Mutex<Vec<serde_json::Value>>,
+    }
+
+    #[async_trait]
+    impl Middleware for SyncEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            if request.url == "https://api.todoist.com/rest/v2/sections" {
+                let sections = serde_json::json!([
+                    {"id": "3", "project_id": "2203306141", "order": 1, "name": "Groceries"},
+                    {"id": "7", "project_id": "2203306141", "order": 2, "name": "Work"},
+                    {"id": "5", "project_id": "2203306141", "order": 3, "name": "Home"},
+                ]);
+                return Ok(Some(TodoistResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: serde_json::to_vec(&sections).unwrap(),
+                }));
+            }
+            let body = match request
+                .url
+                .strip_prefix("https://api.todoist.com/api/v1/id_mappings/")
+            {
+                Some(path) => {
+                    let id = path.rsplit('/').next().unwrap();
+                    serde_json::json!([{"old_id": id, "new_id": format!("v1-{}", id)}])
+                }
+                None => {
+                    let body: serde_json::Value =
+                        serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+                    let command = body["commands"][0].clone();
+                    let uuid = command["uuid"].as_str().unwrap().to_string();
+                    self.commands.lock().unwrap().push(command);
+                    serde_json::json!({"sync_status": {uuid: "ok"}})
+                }
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&body).unwrap(),
+            }))
+        }
+    }
+
+    fn create_config() -> (TodoistConfig, Arc<SyncEndpoint>) {
+        let endpoint = Arc::new(SyncEndpoint {
+            commands: Mutex::new(Vec::new()),
+        });
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(endpoint.clone());
+        (config, endpoint)
+    }
+
+    #[tokio::test]
+    async fn commands_use_v1_ids() {
+        let (config, endpoint) = create_config();
+        archive_section(&config, "7025".into()).await.unwrap();
+        move_section(&config, "7025".into(), "2203306141".into())
+            .await
+            .unwrap();
+        let commands = endpoint.commands.lock().unwrap();
+        assert_eq!(commands[0]["type"], "section_archive");
+        assert_eq!(commands[0]["args"], serde_json::json!({"id": "v1-7025"}));
+        assert_eq!(commands[1]["type"], "section_move");
+        assert_eq!(
+            commands[1]["args"],
+            serde_json::json!({"id": "v1-7025", "project_id": "v1-2203306141"})
+        );
+    }
+
+    #[tokio::test]
+    async fn v1_ids_are_not_translated() {
+        let (config, endpoint) = create_config();
+        let config = config.with_api_version(crate::todoist_config::ApiVersion::V1);
+        unarchive_section(&config, "6X7FxXvX84jHphx2".into())
+            .await
+            .unwrap();
+        let commands = endpoint.commands.lock().unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(
+            commands[0]["args"],
+            serde_json::json!({"id": "6X7FxXvX84jHphx2"})
+        );
+    }
+
+    #[tokio::test]
+    async fn reorder_args_follow_the_ids() {
+        let (config, endpoint) = create_config();
+        reorder_sections(&config, vec!["7".into(), "3".into(), "5".into()])
+            .await
+            .unwrap();
+        let commands = endpoint.commands.lock().unwrap();
+        assert_eq!(commands[0]["type"], "section_reorder");
+        // The section "5" is already third, so it is not sent
+        assert_eq!(
+            commands[0]["args"],
+            serde_json::json!({"sections": [
+                {"id": "v1-7", "section_order": 1},
+                {"id": "v1-3", "section_order": 2},
+            ]})
+        );
+    }
+}
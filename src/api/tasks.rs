@@ -1,13 +1,16 @@
 //! Todoist Tasks API (<https://developer.todoist.com/rest/v2/?shell#tasks>)
 
+use futures_util::stream::{self, Stream, TryStreamExt};
+
 use crate::internal::request::models::{CreateNewTaskArgs, UpdateTaskArgs};
+use crate::internal::request::pagination::{get_all_results, stream_results};
 use crate::internal::request::paths::create_path;
 use crate::internal::request::{
     paths, send_todoist_delete_request, send_todoist_get_request, send_todoist_post_request,
     APIParametersError,
 };
 use crate::model::task::{Task, TaskDuration};
-use crate::todoist_config::TodoistConfig;
+use crate::todoist_config::{ApiVersion, TodoistConfig};
 use crate::TodoistAPIError;
 
 /// Get all active tasks
@@ -26,9 +29,14 @@ use crate::TodoistAPIError;
 /// * filter (with or without lang)
 /// * ids
 /// * label/project_id/section_id
+///
+/// With API v1, filters are sent to a separate endpoint that does not support the other parameters,
+/// so combining `filter` with `project_id`, `section_id`, `label` or `ids` returns an
+/// [APIParametersError](TodoistAPIError::APIParametersError) instead of ignoring them.
 //
 // If you include a filter *and* IDs, only the filter will be used.
 // If you include IDs and project_id, only IDs is used, and so on.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_active_tasks(
     config: &TodoistConfig,
     project_id: Option<String>,
@@ -38,34 +46,47 @@ pub async fn get_active_tasks(
     lang: Option<String>,
     ids: Option<&[String]>,
 ) -> Result<Vec<Task>, TodoistAPIError> {
-    let mut path_parts = vec![paths::TASKS.to_string()];
-    if let Some(project_id) = project_id {
-        path_parts.push(paths::PARAM_PROJECT_ID.into());
-        path_parts.push(project_id);
-    }
-    if let Some(section_id) = section_id {
-        path_parts.push(paths::PARAM_SECTION_ID.into());
-        path_parts.push(section_id);
-    }
-    if let Some(label) = label {
-        path_parts.push(paths::PARAM_LABEL.into());
-        path_parts.push(label);
-    }
-    if let Some(filter) = filter {
-        path_parts.push(paths::PARAM_FILTER.into());
-        path_parts.push(filter);
-    }
-    if let Some(lang) = lang {
-        path_parts.push(paths::PARAM_LANG.into());
-        path_parts.push(lang);
-    }
-    if let Some(ids) = ids {
-        if !ids.is_empty() {
-            path_parts.push(paths::PARAM_IDS.into());
-            path_parts.push(ids.join(","));
-        }
-    }
-    send_todoist_get_request(config, create_path(&path_parts)).await
+    get_all_results(
+        config,
+        get_active_tasks_path(
+            config.get_api_version(),
+            project_id,
+            section_id,
+            label,
+            filter,
+            lang,
+            ids,
+        )?,
+    )
+    .await
+}
+
+/// Get all active tasks as a [Stream]
+///
+/// With API v1, the tasks are requested page by page as the stream is consumed.
+/// API v2 returns every task in a single response, which is read in full before the first task is yielded.
+/// See [get_active_tasks] for the arguments.
+pub fn stream_active_tasks<'a>(
+    config: &'a TodoistConfig,
+    project_id: Option<String>,
+    section_id: Option<String>,
+    label: Option<String>,
+    filter: Option<String>,
+    lang: Option<String>,
+    ids: Option<&[String]>,
+) -> impl Stream<Item = Result<Task, TodoistAPIError>> + 'a {
+    let path = get_active_tasks_path(
+        config.get_api_version(),
+        project_id,
+        section_id,
+        label,
+        filter,
+        lang,
+        ids,
+    );
+    stream::iter([path.map_err(TodoistAPIError::from)])
+        .map_ok(|path| stream_results(config, path))
+        .try_flatten()
 }
 
 /// Create a new [Task]
@@ -84,13 +105,15 @@ pub async fn get_active_tasks(
 /// * `labels` - The task's labels (a list of names that may represent either personal or shared labels)
 /// * `priority` - The task's priority from 1 (normal, default value) to 4 (urgent)
 /// * `due_string` - A [human defined](https://todoist.com/help/articles/205325931) task due date (ex.: "next Monday", "Tomorrow").
-/// Value is set using local (not UTC) time.
-/// To remove the due date, you should set this to `no date` or `no due date`.
+///   Value is set using local (not UTC) time.
+///   To remove the due date, you should set this to `no date` or `no due date`.
 /// * `due_date` - Specific date in `YYYY-MM-DD` format relative to user's timezone
 /// * `due_datetime` - Specific date and time in [RFC3339](https://www.ietf.org/rfc/rfc3339.txt) format in UTC
 /// * `due_lang` - 2-letter code specifying language in case `due_string` is not written in English
 /// * `assignee_id` - The responsible user ID (only applies to shared tasks)
 /// * `duration` - The duration that the task will take
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn create_new_task(
     config: &TodoistConfig,
     content: String,
@@ -143,6 +166,7 @@ pub async fn create_new_task(
 }
 
 /// Get an active (non-completed) [Task] by ID
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn get_active_task(
     config: &TodoistConfig,
     task_id: String,
@@ -163,14 +187,16 @@ pub async fn get_active_task(
 /// * `labels` - The task's labels (a list of names that may represent either personal or shared labels)
 /// * `priority` - The task's priority from 1 (normal, default value) to 4 (urgent)
 /// * `due_string` - A [human defined](https://todoist.com/help/articles/205325931) task due date (ex.: "next Monday", "Tomorrow").
-/// Value is set using local (not UTC) time.
-/// To remove the due date, you should set this to `no date` or `no due date`.
+///   Value is set using local (not UTC) time.
+///   To remove the due date, you should set this to `no date` or `no due date`.
 /// * `due_date` - Specific date in `YYYY-MM-DD` format relative to user's timezone
 /// * `due_datetime` - Specific date and time in [RFC3339](https://www.ietf.org/rfc/rfc3339.txt) format in UTC
 /// * `due_lang` - 2-letter code specifying language in case `due_string` is not written in English
 /// * `assignee_id` - The responsible user ID (only applies to shared tasks)
 /// * `duration` - The duration that the task will take
-/// If specified, `duration` **must** be defined as well.
+///   If specified, `duration` **must** be defined as well.
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn update_task(
     config: &TodoistConfig,
     task_id: String,
@@ -220,8 +246,15 @@ pub async fn update_task(
 /// The command performs in the same way as the official clients:
 /// * Regular tasks are marked complete and moved to history, along with their subtasks.
 /// * Tasks with [recurring due dates](https://todoist.com/help/articles/360000636289) will be scheduled to their next occurrence.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn close_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
-    send_todoist_delete_request(config, create_path(&[paths::TASKS, &task_id, paths::CLOSE])).await
+    send_todoist_post_request::<(), ()>(
+        config,
+        create_path(&[paths::TASKS, &task_id, paths::CLOSE]),
+        None,
+        false,
+    )
+    .await
 }
 
 /// Reopens a [Task]
@@ -229,6 +262,7 @@ pub async fn close_task(config: &TodoistConfig, task_id: String) -> Result<(), T
 /// Any ancestor items or sections will also be marked as uncompleted and restored from history.
 ///
 /// The reinstated items and sections will appear at the end of the list within their parent, after any previously active items.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn reopen_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
     send_todoist_post_request::<(), ()>(
         config,
@@ -240,10 +274,71 @@ pub async fn reopen_task(config: &TodoistConfig, task_id: String) -> Result<(),
 }
 
 /// Deletes a [Task]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub async fn delete_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
     send_todoist_delete_request(config, get_task_path(task_id)).await
 }
 
+fn get_active_tasks_path(
+    api_version: ApiVersion,
+    project_id: Option<String>,
+    section_id: Option<String>,
+    label: Option<String>,
+    filter: Option<String>,
+    lang: Option<String>,
+    ids: Option<&[String]>,
+) -> Result<String, APIParametersError> {
+    // API v1 moved filtering to its own endpoint, which ignores the other parameters
+    if let (ApiVersion::V1, Some(filter)) = (api_version, &filter) {
+        let has_ids = ids.is_some_and(|ids| !ids.is_empty());
+        if project_id.is_some() || section_id.is_some() || label.is_some() || has_ids {
+            return Err(APIParametersError {
+                message: "With API v1, a filter cannot be combined with project_id, section_id, label or ids"
+                    .into(),
+            });
+        }
+        let mut path_parts = vec![
+            paths::TASKS.to_string(),
+            paths::FILTER.into(),
+            paths::PARAM_QUERY.into(),
+            filter.clone(),
+        ];
+        if let Some(lang) = lang {
+            path_parts.push(paths::PARAM_LANG.into());
+            path_parts.push(lang);
+        }
+        return Ok(create_path(&path_parts));
+    }
+    let mut path_parts = vec![paths::TASKS.to_string()];
+    if let Some(project_id) = project_id {
+        path_parts.push(paths::PARAM_PROJECT_ID.into());
+        path_parts.push(project_id);
+    }
+    if let Some(section_id) = section_id {
+        path_parts.push(paths::PARAM_SECTION_ID.into());
+        path_parts.push(section_id);
+    }
+    if let Some(label) = label {
+        path_parts.push(paths::PARAM_LABEL.into());
+        path_parts.push(label);
+    }
+    if let Some(filter) = filter {
+        path_parts.push(paths::PARAM_FILTER.into());
+        path_parts.push(filter);
+    }
+    if let Some(lang) = lang {
+        path_parts.push(paths::PARAM_LANG.into());
+        path_parts.push(lang);
+    }
+    if let Some(ids) = ids {
+        if !ids.is_empty() {
+            path_parts.push(paths::PARAM_IDS.into());
+            path_parts.push(ids.join(","));
+        }
+    }
+    Ok(create_path(&path_parts))
+}
+
 fn validate_task_args(
     due_string: &Option<String>,
     due_date: &Option<String>,
@@ -252,7 +347,7 @@ fn validate_task_args(
     due_lang: &Option<String>,
     duration: &Option<TaskDuration>,
 ) -> Result<(), TodoistAPIError> {
-    let due_types = vec![
+    let due_types = [
         due_string.to_owned(),
         due_date.to_owned(),
         due_datetime.to_owned(),
@@ -419,6 +514,52 @@ fn get_task_path(task_id: String) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn active_tasks_path() {
+        let ids = ["1".to_string(), "2".to_string()];
+        assert_eq!(
+            get_active_tasks_path(
+                ApiVersion::V2,
+                None,
+                None,
+                None,
+                Some("today".into()),
+                Some("en".into()),
+                Some(&ids)
+            )
+            .unwrap(),
+            "/tasks?filter=today&lang=en&ids=1,2"
+        );
+        assert_eq!(
+            get_active_tasks_path(
+                ApiVersion::V1,
+                None,
+                None,
+                None,
+                Some("today".into()),
+                Some("en".into()),
+                Some(&[])
+            )
+            .unwrap(),
+            "/tasks/filter?query=today&lang=en"
+        );
+        assert!(get_active_tasks_path(
+            ApiVersion::V1,
+            Some("2203306141".into()),
+            None,
+            None,
+            Some("today".into()),
+            Some("en".into()),
+            Some(&ids)
+        )
+        .is_err());
+        assert_eq!(
+            get_active_tasks_path(ApiVersion::V1, None, None, None, None, None, Some(&ids))
+                .unwrap(),
+            "/tasks?ids=1,2"
+        );
+    }
+
     #[test]
     fn validate_yyyy_mm_dd_valid() {
         assert!(validate_yyyy_mm_dd("2020-12-15".into()).is_none());
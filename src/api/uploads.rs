@@ -0,0 +1,205 @@
+//! Todoist Uploads API (<https://developer.todoist.com/api/v1/#tag/Uploads>)
+//!
+//! Uploaded files can be attached to a comment with [crate::comments::create_new_comment].
+//! API v2 has no uploads endpoint, so uploads are sent to API v1 with any
+//! [ApiVersion](crate::todoist_config::ApiVersion).
+
+use std::fmt::{Debug, Formatter};
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::internal::request::multipart::MultipartForm;
+use crate::internal::request::{paths, send_todoist_multipart_request, APIParametersError};
+use crate::model::comment::CommentAttachment;
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
+/// The maximum size of an uploaded file in bytes (100 MiB)
+///
+/// Todoist may reject smaller files depending on the plan of the user.
+pub const MAX_UPLOAD_SIZE: u64 = 100 * 1024 * 1024;
+
+/// The content of a file to upload with [upload_file]
+pub enum UploadSource {
+    /// A file on the disk, uploaded with the same file name
+    Path(PathBuf),
+    /// A file that is already in memory
+    Bytes {
+        /// The name of the file
+        file_name: String,
+        /// The content of the file
+        content: Vec<u8>,
+    },
+    /// A file read until the end of `reader`
+    Reader {
+        /// The name of the file
+        file_name: String,
+        /// The reader of the content of the file
+        reader: Box<dyn AsyncRead + Send + Unpin>,
+    },
+}
+
+impl Debug for UploadSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadSource::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            UploadSource::Bytes { file_name, content } => f
+                .debug_struct("Bytes")
+                .field("file_name", file_name)
+                .field("size", &content.len())
+                .finish(),
+            UploadSource::Reader { file_name, .. } => f
+                .debug_struct("Reader")
+                .field("file_name", file_name)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Uploads a file, returning a [CommentAttachment] that can be passed to
+/// [crate::comments::create_new_comment]
+///
+/// The MIME type of the file is detected from its extension,
+/// and files that are empty or larger than [MAX_UPLOAD_SIZE] are rejected before being sent.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn upload_file(
+    config: &TodoistConfig,
+    source: UploadSource,
+) -> Result<CommentAttachment, TodoistAPIError> {
+    let (file_name, reader) = open_source(source).await?;
+    let mime_type = mime_guess::from_path(&file_name).first_or_octet_stream();
+    let mut form = MultipartForm::new();
+    form.add_text("file_name", &file_name);
+    // Reading one byte past the limit is enough to know that the file is too large
+    let size = form
+        .add_file(
+            "file",
+            &file_name,
+            mime_type.essence_str(),
+            reader.take(MAX_UPLOAD_SIZE + 1),
+        )
+        .await?;
+    check_size(&file_name, size)?;
+    send_todoist_multipart_request(config, paths::UPLOADS, form).await
+}
+
+/// Returns the name of the file and a reader of its content
+async fn open_source(
+    source: UploadSource,
+) -> Result<(String, Box<dyn AsyncRead + Send + Unpin>), TodoistAPIError> {
+    Ok(match source {
+        UploadSource::Path(path) => {
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .ok_or_else(|| APIParametersError {
+                    message: format!("{} is not a file", path.display()),
+                })?;
+            // Checked before reading the file, so that large files are not read into memory
+            check_size(&file_name, tokio::fs::metadata(&path).await?.len())?;
+            (file_name, Box::new(tokio::fs::File::open(&path).await?))
+        }
+        UploadSource::Bytes { file_name, content } => (file_name, Box::new(Cursor::new(content))),
+        UploadSource::Reader { file_name, reader } => (file_name, reader),
+    })
+}
+
+fn check_size(file_name: &str, size: u64) -> Result<(), APIParametersError> {
+    if size == 0 {
+        return Err(APIParametersError {
+            message: format!("{} is empty", file_name),
+        });
+    }
+    if size > MAX_UPLOAD_SIZE {
+        return Err(APIParametersError {
+            message: format!(
+                "{} is larger than the maximum upload size of {} bytes",
+                file_name, MAX_UPLOAD_SIZE
+            ),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::{HeaderMap, CONTENT_TYPE};
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    struct UploadEndpoint;
+
+    #[async_trait]
+    impl Middleware for UploadEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            assert_eq!(request.url, "https://api.todoist.com/api/v1/uploads");
+            let content_type = request.headers[CONTENT_TYPE].to_str().unwrap();
+            assert!(content_type.starts_with("multipart/form-data; boundary="));
+            let body = String::from_utf8(request.body.clone().unwrap().to_vec()).unwrap();
+            assert!(body.contains(
+                "Content-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n\
+                 Content-Type: text/plain\r\n\r\nBuy Milk\r\n"
+            ));
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: br#"{"file_name": "notes.txt", "file_size": 8, "file_type": "text/plain",
+                    "file_url": "https://files.todoist.com/notes.txt", "resource_type": "file",
+                    "upload_state": "completed"}"#
+                    .to_vec(),
+            }))
+        }
+    }
+
+    fn create_config() -> TodoistConfig {
+        TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(UploadEndpoint)
+    }
+
+    #[tokio::test]
+    async fn uploads_from_a_reader() {
+        let attachment = upload_file(
+            &create_config(),
+            UploadSource::Reader {
+                file_name: "notes.txt".into(),
+                reader: Box::new(&b"Buy Milk"[..]),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(attachment.file_url, "https://files.todoist.com/notes.txt");
+        assert_eq!(attachment.extra["upload_state"], "completed");
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_files() {
+        let error = upload_file(
+            &create_config(),
+            UploadSource::Bytes {
+                file_name: "notes.txt".into(),
+                content: Vec::new(),
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "API parameters error - notes.txt is empty"
+        );
+    }
+
+    #[test]
+    fn size_limit() {
+        assert!(check_size("a.pdf", MAX_UPLOAD_SIZE).is_ok());
+        assert!(check_size("a.pdf", MAX_UPLOAD_SIZE + 1).is_err());
+    }
+}
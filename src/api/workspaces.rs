@@ -0,0 +1,156 @@
+//! Todoist Workspaces API (<https://developer.todoist.com/api/v1/#tag/Workspace>)
+//!
+//! Workspaces were added in API v1, which is used here whatever the [ApiVersion] of the config.
+
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+
+use crate::internal::request::pagination::stream_versioned_results;
+use crate::internal::request::paths;
+use crate::internal::request::sync::read_resources;
+use crate::model::project::Project;
+use crate::model::workspace::{Workspace, WorkspaceMember};
+use crate::todoist_config::{ApiVersion, TodoistConfig};
+use crate::TodoistAPIError;
+
+#[derive(Deserialize)]
+struct WorkspacesResource {
+    workspaces: Vec<SyncResource<Workspace>>,
+}
+
+#[derive(Deserialize)]
+struct WorkspaceUsersResource {
+    workspace_users: Vec<SyncResource<WorkspaceMember>>,
+}
+
+#[derive(Deserialize)]
+struct SyncResource<R> {
+    #[serde(flatten)]
+    resource: R,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+/// Get all [workspaces](Workspace) that the current user is a member of
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_all_workspaces(config: &TodoistConfig) -> Result<Vec<Workspace>, TodoistAPIError> {
+    let resource: WorkspacesResource = read_resources(config, &["workspaces"]).await?;
+    Ok(resource
+        .workspaces
+        .into_iter()
+        .filter(|workspace| !workspace.is_deleted)
+        .map(|workspace| workspace.resource)
+        .collect())
+}
+
+/// Get all [members](WorkspaceMember) of a [Workspace]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_workspace_members(
+    config: &TodoistConfig,
+    workspace_id: String,
+) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+    let resource: WorkspaceUsersResource = read_resources(config, &["workspace_users"]).await?;
+    Ok(resource
+        .workspace_users
+        .into_iter()
+        .filter(|member| !member.is_deleted && member.resource.workspace_id == workspace_id)
+        .map(|member| member.resource)
+        .collect())
+}
+
+/// Get all [projects](Project) of a [Workspace] that the current user has joined
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn get_workspace_projects(
+    config: &TodoistConfig,
+    workspace_id: String,
+) -> Result<Vec<Project>, TodoistAPIError> {
+    let projects: Vec<Project> =
+        stream_versioned_results(config, ApiVersion::V1, paths::PROJECTS.to_string())
+            .try_collect()
+            .await?;
+    Ok(projects
+        .into_iter()
+        .filter(|project| project.workspace_id.as_ref() == Some(&workspace_id))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+    use crate::model::workspace::{WorkspacePlan, WorkspaceRole};
+
+    struct WorkspacesEndpoint;
+
+    #[async_trait]
+    impl Middleware for WorkspacesEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let body = match request.url.as_str() {
+                "https://api.todoist.com/api/v1/sync" => {
+                    r#"{"sync_token": "a", "workspaces": [
+                        {"id": "1", "name": "Acme", "description": null, "plan": "BUSINESS",
+                         "role": "ADMIN", "creator_id": "7", "is_guest_allowed": true,
+                         "is_deleted": false},
+                        {"id": "2", "name": "Old", "plan": "STARTER", "role": "GUEST",
+                         "is_deleted": true},
+                        {"id": "3", "name": "Acme Group", "plan": "ENTERPRISE", "role": "OWNER",
+                         "is_deleted": false}
+                    ]}"#
+                }
+                "https://api.todoist.com/api/v1/projects" => {
+                    r#"{"next_cursor": null, "results": [
+                        {"id": "10", "name": "Personal", "color": "grey", "parent_id": null,
+                         "child_order": 1, "view_style": "list", "workspace_id": null},
+                        {"id": "11", "name": "Roadmap", "color": "red", "parent_id": null,
+                         "child_order": 2, "view_style": "board", "workspace_id": "1",
+                         "folder_id": "5"}
+                    ]}"#
+                }
+                url => panic!("unexpected URL {}", url),
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.as_bytes().to_vec(),
+            }))
+        }
+    }
+
+    fn create_config() -> TodoistConfig {
+        TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(WorkspacesEndpoint)
+    }
+
+    #[tokio::test]
+    async fn deleted_workspaces_are_skipped() {
+        let workspaces = get_all_workspaces(&create_config()).await.unwrap();
+        assert_eq!(workspaces.len(), 2);
+        assert_eq!(workspaces[0].plan, WorkspacePlan::Business);
+        assert_eq!(workspaces[0].role, WorkspaceRole::Admin);
+        assert_eq!(workspaces[0].extra["is_guest_allowed"], true);
+        assert_eq!(
+            workspaces[1].plan,
+            WorkspacePlan::Unknown("ENTERPRISE".into())
+        );
+        assert_eq!(workspaces[1].role, WorkspaceRole::Unknown("OWNER".into()));
+    }
+
+    #[tokio::test]
+    async fn workspace_projects_are_filtered() {
+        let projects = get_workspace_projects(&create_config(), "1".into())
+            .await
+            .unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].id, "11");
+        assert_eq!(projects[0].folder_id.as_deref(), Some("5"));
+        assert!(projects[0].is_workspace_project());
+    }
+}
@@ -0,0 +1,49 @@
+//! Blocking version of the downloads of comment attachments
+
+use std::path::{Path, PathBuf};
+
+use tokio::io::AsyncWrite;
+
+use crate::attachments::DownloadedFile;
+use crate::internal::request::TodoistAPIError;
+use crate::model::comment::CommentAttachment;
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::attachments::download_attachment]
+pub fn download_attachment<W: AsyncWrite + Unpin + ?Sized>(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    writer: &mut W,
+) -> Result<DownloadedFile, TodoistAPIError> {
+    super::block_on(crate::attachments::download_attachment(
+        config, attachment, writer,
+    ))
+}
+
+/// Blocking version of [crate::attachments::download_attachment_to_path]
+pub fn download_attachment_to_path(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    path: &Path,
+    expected_sha256: Option<&str>,
+) -> Result<DownloadedFile, TodoistAPIError> {
+    super::block_on(crate::attachments::download_attachment_to_path(
+        config,
+        attachment,
+        path,
+        expected_sha256,
+    ))
+}
+
+/// Blocking version of [crate::attachments::download_attachment_cached]
+pub fn download_attachment_cached(
+    config: &TodoistConfig,
+    attachment: &CommentAttachment,
+    cache_directory: &Path,
+) -> Result<PathBuf, TodoistAPIError> {
+    super::block_on(crate::attachments::download_attachment_cached(
+        config,
+        attachment,
+        cache_directory,
+    ))
+}
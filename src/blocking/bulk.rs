@@ -0,0 +1,57 @@
+//! Blocking version of the bulk helpers (see [crate::bulk])
+
+use crate::bulk::{NewTask, TaskUpdate};
+use crate::internal::request::TodoistAPIError;
+use crate::model::task::Task;
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::bulk::close_tasks]
+pub fn close_tasks(
+    config: &TodoistConfig,
+    task_ids: Vec<String>,
+    concurrency: usize,
+) -> Vec<Result<(), TodoistAPIError>> {
+    super::block_on(crate::bulk::close_tasks(config, task_ids, concurrency))
+}
+
+/// Blocking version of [crate::bulk::delete_tasks]
+pub fn delete_tasks(
+    config: &TodoistConfig,
+    task_ids: Vec<String>,
+    concurrency: usize,
+) -> Vec<Result<(), TodoistAPIError>> {
+    super::block_on(crate::bulk::delete_tasks(config, task_ids, concurrency))
+}
+
+/// Blocking version of [crate::bulk::create_tasks]
+pub fn create_tasks(
+    config: &TodoistConfig,
+    tasks: Vec<NewTask>,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    super::block_on(crate::bulk::create_tasks(config, tasks, concurrency))
+}
+
+/// Blocking version of [crate::bulk::update_tasks]
+pub fn update_tasks(
+    config: &TodoistConfig,
+    updates: Vec<TaskUpdate>,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    super::block_on(crate::bulk::update_tasks(config, updates, concurrency))
+}
+
+/// Blocking version of [crate::bulk::add_label_to_tasks]
+pub fn add_label_to_tasks(
+    config: &TodoistConfig,
+    tasks: Vec<Task>,
+    label: String,
+    concurrency: usize,
+) -> Vec<Result<Task, TodoistAPIError>> {
+    super::block_on(crate::bulk::add_label_to_tasks(
+        config,
+        tasks,
+        label,
+        concurrency,
+    ))
+}
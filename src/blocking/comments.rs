@@ -0,0 +1,53 @@
+//! Blocking version of the Todoist Comments API (<https://developer.todoist.com/rest/v2/?shell#comments>)
+
+pub use crate::comments::TaskOrProjectID;
+use crate::internal::request::TodoistAPIError;
+use crate::model::comment::{Comment, CommentAttachment};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::comments::get_all_comments]
+pub fn get_all_comments(
+    config: &TodoistConfig,
+    task_or_project_id: &TaskOrProjectID,
+) -> Result<Vec<Comment>, TodoistAPIError> {
+    super::block_on(crate::comments::get_all_comments(
+        config,
+        task_or_project_id,
+    ))
+}
+
+/// Blocking version of [crate::comments::create_new_comment]
+pub fn create_new_comment(
+    config: &TodoistConfig,
+    task_or_project_id: &TaskOrProjectID,
+    content: String,
+    attachment: Option<CommentAttachment>,
+    uids_to_notify: Option<Vec<String>>,
+) -> Result<Comment, TodoistAPIError> {
+    super::block_on(crate::comments::create_new_comment(
+        config,
+        task_or_project_id,
+        content,
+        attachment,
+        uids_to_notify,
+    ))
+}
+
+/// Blocking version of [crate::comments::get_comment]
+pub fn get_comment(config: &TodoistConfig, comment_id: String) -> Result<Comment, TodoistAPIError> {
+    super::block_on(crate::comments::get_comment(config, comment_id))
+}
+
+/// Blocking version of [crate::comments::update_comment]
+pub fn update_comment(
+    config: &TodoistConfig,
+    comment_id: String,
+    content: String,
+) -> Result<Comment, TodoistAPIError> {
+    super::block_on(crate::comments::update_comment(config, comment_id, content))
+}
+
+/// Blocking version of [crate::comments::delete_comment]
+pub fn delete_comment(config: &TodoistConfig, comment_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::comments::delete_comment(config, comment_id))
+}
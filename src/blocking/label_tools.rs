@@ -0,0 +1,36 @@
+//! Blocking version of the label tools (see [crate::label_tools])
+
+use crate::internal::request::TodoistAPIError;
+use crate::label_tools::{LabelMergeReport, LabelRenameReport, OrphanLabels};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::label_tools::merge_labels]
+pub fn merge_labels(
+    config: &TodoistConfig,
+    from: String,
+    into: String,
+    concurrency: usize,
+) -> Result<LabelMergeReport, TodoistAPIError> {
+    super::block_on(crate::label_tools::merge_labels(
+        config,
+        from,
+        into,
+        concurrency,
+    ))
+}
+
+/// Blocking version of [crate::label_tools::rename_label_everywhere]
+pub fn rename_label_everywhere(
+    config: &TodoistConfig,
+    name: String,
+    new_name: String,
+) -> Result<LabelRenameReport, TodoistAPIError> {
+    super::block_on(crate::label_tools::rename_label_everywhere(
+        config, name, new_name,
+    ))
+}
+
+/// Blocking version of [crate::label_tools::find_orphan_labels]
+pub fn find_orphan_labels(config: &TodoistConfig) -> Result<OrphanLabels, TodoistAPIError> {
+    super::block_on(crate::label_tools::find_orphan_labels(config))
+}
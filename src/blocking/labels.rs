@@ -0,0 +1,87 @@
+//! Blocking version of the Todoist Labels API (<https://developer.todoist.com/rest/v2/?shell#labels>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::color::Color;
+use crate::model::label::PersonalLabel;
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::labels::get_all_personal_labels]
+pub fn get_all_personal_labels(
+    config: &TodoistConfig,
+) -> Result<Vec<PersonalLabel>, TodoistAPIError> {
+    super::block_on(crate::labels::get_all_personal_labels(config))
+}
+
+/// Blocking version of [crate::labels::create_new_personal_label]
+pub fn create_new_personal_label(
+    config: &TodoistConfig,
+    name: String,
+    order: Option<u32>,
+    color: Option<Color>,
+    is_favorite: Option<bool>,
+) -> Result<PersonalLabel, TodoistAPIError> {
+    super::block_on(crate::labels::create_new_personal_label(
+        config,
+        name,
+        order,
+        color,
+        is_favorite,
+    ))
+}
+
+/// Blocking version of [crate::labels::get_personal_label]
+pub fn get_personal_label(
+    config: &TodoistConfig,
+    label_id: String,
+) -> Result<PersonalLabel, TodoistAPIError> {
+    super::block_on(crate::labels::get_personal_label(config, label_id))
+}
+
+/// Blocking version of [crate::labels::update_personal_label]
+pub fn update_personal_label(
+    config: &TodoistConfig,
+    label_id: String,
+    name: Option<String>,
+    order: Option<u32>,
+    color: Option<Color>,
+    is_favorite: Option<bool>,
+) -> Result<PersonalLabel, TodoistAPIError> {
+    super::block_on(crate::labels::update_personal_label(
+        config,
+        label_id,
+        name,
+        order,
+        color,
+        is_favorite,
+    ))
+}
+
+/// Blocking version of [crate::labels::delete_personal_label]
+pub fn delete_personal_label(
+    config: &TodoistConfig,
+    label_id: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::labels::delete_personal_label(config, label_id))
+}
+
+/// Blocking version of [crate::labels::get_all_shared_labels]
+pub fn get_all_shared_labels(
+    config: &TodoistConfig,
+    omit_personal: Option<bool>,
+) -> Result<Vec<String>, TodoistAPIError> {
+    super::block_on(crate::labels::get_all_shared_labels(config, omit_personal))
+}
+
+/// Blocking version of [crate::labels::rename_shared_labels]
+pub fn rename_shared_labels(
+    config: &TodoistConfig,
+    name: String,
+    new_name: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::labels::rename_shared_labels(config, name, new_name))
+}
+
+/// Blocking version of [crate::labels::remove_shared_labels]
+pub fn remove_shared_labels(config: &TodoistConfig, name: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::labels::remove_shared_labels(config, name))
+}
@@ -0,0 +1,38 @@
+//! A blocking (synchronous) version of the Todoist API
+//!
+//! Every function mirrors the `async` function with the same name in [crate::api](crate),
+//! using the same models and validation.
+//! The requests are run on an internal single-threaded `tokio` runtime,
+//! so no runtime needs to be set up by the caller.
+//!
+//! These functions must **not** be called from within an async runtime, as they will panic.
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::{Builder, Runtime};
+
+pub mod attachments;
+pub mod bulk;
+pub mod comments;
+pub mod label_tools;
+pub mod labels;
+pub mod projects;
+pub mod reminders;
+pub mod reorder;
+pub mod sections;
+pub mod tasks;
+pub mod uploads;
+pub mod workspaces;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create the tokio runtime for the blocking API")
+        })
+        .block_on(future)
+}
@@ -0,0 +1,148 @@
+//! Blocking version of the Todoist Projects API (<https://developer.todoist.com/rest/v2/?shell#projects>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::collaborator::{Collaborator, CollaboratorRole};
+use crate::model::color::Color;
+use crate::model::project::{Project, ProjectViewStyle};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::projects::get_all_projects]
+pub fn get_all_projects(config: &TodoistConfig) -> Result<Vec<Project>, TodoistAPIError> {
+    super::block_on(crate::projects::get_all_projects(config))
+}
+
+/// Blocking version of [crate::projects::create_new_project]
+pub fn create_new_project(
+    config: &TodoistConfig,
+    name: String,
+    parent_id: Option<String>,
+    color: Option<Color>,
+    is_favorite: Option<bool>,
+    view_style: Option<ProjectViewStyle>,
+) -> Result<Project, TodoistAPIError> {
+    super::block_on(crate::projects::create_new_project(
+        config,
+        name,
+        parent_id,
+        color,
+        is_favorite,
+        view_style,
+    ))
+}
+
+/// Blocking version of [crate::projects::get_project]
+pub fn get_project(config: &TodoistConfig, project_id: String) -> Result<Project, TodoistAPIError> {
+    super::block_on(crate::projects::get_project(config, project_id))
+}
+
+/// Blocking version of [crate::projects::update_project]
+pub fn update_project(
+    config: &TodoistConfig,
+    project_id: String,
+    name: Option<String>,
+    color: Option<Color>,
+    is_favorite: Option<bool>,
+    view_style: Option<ProjectViewStyle>,
+) -> Result<Project, TodoistAPIError> {
+    super::block_on(crate::projects::update_project(
+        config,
+        project_id,
+        name,
+        color,
+        is_favorite,
+        view_style,
+    ))
+}
+
+/// Blocking version of [crate::projects::delete_project]
+pub fn delete_project(config: &TodoistConfig, project_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::projects::delete_project(config, project_id))
+}
+
+/// Blocking version of [crate::projects::get_all_collaborators]
+pub fn get_all_collaborators(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Vec<Collaborator>, TodoistAPIError> {
+    super::block_on(crate::projects::get_all_collaborators(config, project_id))
+}
+
+/// Blocking version of [crate::projects::get_collaborators_with_roles]
+pub fn get_collaborators_with_roles(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Vec<Collaborator>, TodoistAPIError> {
+    super::block_on(crate::projects::get_collaborators_with_roles(
+        config, project_id,
+    ))
+}
+
+/// Blocking version of [crate::projects::share_project]
+pub fn share_project(
+    config: &TodoistConfig,
+    project_id: String,
+    email: String,
+    role: Option<CollaboratorRole>,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::projects::share_project(
+        config, project_id, email, role,
+    ))
+}
+
+/// Blocking version of [crate::projects::delete_collaborator]
+pub fn delete_collaborator(
+    config: &TodoistConfig,
+    project_id: String,
+    email: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::projects::delete_collaborator(
+        config, project_id, email,
+    ))
+}
+
+/// Blocking version of [crate::projects::accept_invitation]
+pub fn accept_invitation(
+    config: &TodoistConfig,
+    invitation_id: String,
+    invitation_secret: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::projects::accept_invitation(
+        config,
+        invitation_id,
+        invitation_secret,
+    ))
+}
+
+/// Blocking version of [crate::projects::reject_invitation]
+pub fn reject_invitation(
+    config: &TodoistConfig,
+    invitation_id: String,
+    invitation_secret: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::projects::reject_invitation(
+        config,
+        invitation_id,
+        invitation_secret,
+    ))
+}
+
+/// Blocking version of [crate::projects::archive_project]
+pub fn archive_project(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Project, TodoistAPIError> {
+    super::block_on(crate::projects::archive_project(config, project_id))
+}
+
+/// Blocking version of [crate::projects::unarchive_project]
+pub fn unarchive_project(
+    config: &TodoistConfig,
+    project_id: String,
+) -> Result<Project, TodoistAPIError> {
+    super::block_on(crate::projects::unarchive_project(config, project_id))
+}
+
+/// Blocking version of [crate::projects::get_archived_projects]
+pub fn get_archived_projects(config: &TodoistConfig) -> Result<Vec<Project>, TodoistAPIError> {
+    super::block_on(crate::projects::get_archived_projects(config))
+}
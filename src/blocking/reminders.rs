@@ -0,0 +1,61 @@
+//! Blocking version of the Todoist Reminders API (<https://developer.todoist.com/api/v1/#tag/Sync/Reminders>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::reminder::{Reminder, ReminderType};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::reminders::get_all_reminders]
+pub fn get_all_reminders(config: &TodoistConfig) -> Result<Vec<Reminder>, TodoistAPIError> {
+    super::block_on(crate::reminders::get_all_reminders(config))
+}
+
+/// Blocking version of [crate::reminders::get_task_reminders]
+pub fn get_task_reminders(
+    config: &TodoistConfig,
+    task_id: String,
+) -> Result<Vec<Reminder>, TodoistAPIError> {
+    super::block_on(crate::reminders::get_task_reminders(config, task_id))
+}
+
+/// Blocking version of [crate::reminders::get_reminder]
+pub fn get_reminder(
+    config: &TodoistConfig,
+    reminder_id: String,
+) -> Result<Reminder, TodoistAPIError> {
+    super::block_on(crate::reminders::get_reminder(config, reminder_id))
+}
+
+/// Blocking version of [crate::reminders::create_new_reminder]
+pub fn create_new_reminder(
+    config: &TodoistConfig,
+    task_id: String,
+    reminder_type: ReminderType,
+    notify_uid: Option<String>,
+) -> Result<Reminder, TodoistAPIError> {
+    super::block_on(crate::reminders::create_new_reminder(
+        config,
+        task_id,
+        reminder_type,
+        notify_uid,
+    ))
+}
+
+/// Blocking version of [crate::reminders::update_reminder]
+pub fn update_reminder(
+    config: &TodoistConfig,
+    reminder_id: String,
+    reminder_type: Option<ReminderType>,
+    notify_uid: Option<String>,
+) -> Result<Reminder, TodoistAPIError> {
+    super::block_on(crate::reminders::update_reminder(
+        config,
+        reminder_id,
+        reminder_type,
+        notify_uid,
+    ))
+}
+
+/// Blocking version of [crate::reminders::delete_reminder]
+pub fn delete_reminder(config: &TodoistConfig, reminder_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::reminders::delete_reminder(config, reminder_id))
+}
@@ -0,0 +1,23 @@
+//! Blocking version of the reordering API (see [crate::reorder])
+
+use crate::internal::request::TodoistAPIError;
+use crate::reorder::{OrderChange, ReorderTarget};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::reorder::reorder]
+pub fn reorder(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    ids: Vec<String>,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::reorder::reorder(config, target, ids))
+}
+
+/// Blocking version of [crate::reorder::apply_order_changes]
+pub fn apply_order_changes(
+    config: &TodoistConfig,
+    target: ReorderTarget,
+    changes: Vec<OrderChange>,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::reorder::apply_order_changes(config, target, changes))
+}
@@ -0,0 +1,76 @@
+//! Blocking version of the Todoist Sections API (<https://developer.todoist.com/rest/v2/?shell#sections>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::section::Section;
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::sections::get_all_sections]
+pub fn get_all_sections(
+    config: &TodoistConfig,
+    project_id: Option<String>,
+) -> Result<Vec<Section>, TodoistAPIError> {
+    super::block_on(crate::sections::get_all_sections(config, project_id))
+}
+
+/// Blocking version of [crate::sections::create_new_section]
+pub fn create_new_section(
+    config: &TodoistConfig,
+    project_id: String,
+    name: String,
+    order: Option<u32>,
+) -> Result<Section, TodoistAPIError> {
+    super::block_on(crate::sections::create_new_section(
+        config, project_id, name, order,
+    ))
+}
+
+/// Blocking version of [crate::sections::get_section]
+pub fn get_section(config: &TodoistConfig, section_id: String) -> Result<Section, TodoistAPIError> {
+    super::block_on(crate::sections::get_section(config, section_id))
+}
+
+/// Blocking version of [crate::sections::update_section]
+pub fn update_section(
+    config: &TodoistConfig,
+    section_id: String,
+    name: String,
+) -> Result<Section, TodoistAPIError> {
+    super::block_on(crate::sections::update_section(config, section_id, name))
+}
+
+/// Blocking version of [crate::sections::delete_section]
+pub fn delete_section(config: &TodoistConfig, section_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::sections::delete_section(config, section_id))
+}
+
+/// Blocking version of [crate::sections::archive_section]
+pub fn archive_section(config: &TodoistConfig, section_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::sections::archive_section(config, section_id))
+}
+
+/// Blocking version of [crate::sections::unarchive_section]
+pub fn unarchive_section(
+    config: &TodoistConfig,
+    section_id: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::sections::unarchive_section(config, section_id))
+}
+
+/// Blocking version of [crate::sections::move_section]
+pub fn move_section(
+    config: &TodoistConfig,
+    section_id: String,
+    project_id: String,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::sections::move_section(
+        config, section_id, project_id,
+    ))
+}
+
+/// Blocking version of [crate::sections::reorder_sections]
+pub fn reorder_sections(
+    config: &TodoistConfig,
+    section_ids: Vec<String>,
+) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::sections::reorder_sections(config, section_ids))
+}
@@ -0,0 +1,110 @@
+//! Blocking version of the Todoist Tasks API (<https://developer.todoist.com/rest/v2/?shell#tasks>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::task::{Task, TaskDuration};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::tasks::get_active_tasks]
+pub fn get_active_tasks(
+    config: &TodoistConfig,
+    project_id: Option<String>,
+    section_id: Option<String>,
+    label: Option<String>,
+    filter: Option<String>,
+    lang: Option<String>,
+    ids: Option<&[String]>,
+) -> Result<Vec<Task>, TodoistAPIError> {
+    super::block_on(crate::tasks::get_active_tasks(
+        config, project_id, section_id, label, filter, lang, ids,
+    ))
+}
+
+/// Blocking version of [crate::tasks::create_new_task]
+#[allow(clippy::too_many_arguments)]
+pub fn create_new_task(
+    config: &TodoistConfig,
+    content: String,
+    description: Option<String>,
+    project_id: Option<String>,
+    section_id: Option<String>,
+    parent_id: Option<String>,
+    order: Option<u32>,
+    labels: Option<Vec<String>>,
+    priority: Option<u8>,
+    due_string: Option<String>,
+    due_date: Option<String>,
+    due_datetime: Option<String>,
+    due_lang: Option<String>,
+    assignee_id: Option<String>,
+    duration: Option<TaskDuration>,
+) -> Result<Task, TodoistAPIError> {
+    super::block_on(crate::tasks::create_new_task(
+        config,
+        content,
+        description,
+        project_id,
+        section_id,
+        parent_id,
+        order,
+        labels,
+        priority,
+        due_string,
+        due_date,
+        due_datetime,
+        due_lang,
+        assignee_id,
+        duration,
+    ))
+}
+
+/// Blocking version of [crate::tasks::get_active_task]
+pub fn get_active_task(config: &TodoistConfig, task_id: String) -> Result<Task, TodoistAPIError> {
+    super::block_on(crate::tasks::get_active_task(config, task_id))
+}
+
+/// Blocking version of [crate::tasks::update_task]
+#[allow(clippy::too_many_arguments)]
+pub fn update_task(
+    config: &TodoistConfig,
+    task_id: String,
+    content: Option<String>,
+    description: Option<String>,
+    labels: Option<Vec<String>>,
+    priority: Option<u8>,
+    due_string: Option<String>,
+    due_date: Option<String>,
+    due_datetime: Option<String>,
+    due_lang: Option<String>,
+    assignee_id: Option<String>,
+    duration: Option<TaskDuration>,
+) -> Result<Task, TodoistAPIError> {
+    super::block_on(crate::tasks::update_task(
+        config,
+        task_id,
+        content,
+        description,
+        labels,
+        priority,
+        due_string,
+        due_date,
+        due_datetime,
+        due_lang,
+        assignee_id,
+        duration,
+    ))
+}
+
+/// Blocking version of [crate::tasks::close_task]
+pub fn close_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::tasks::close_task(config, task_id))
+}
+
+/// Blocking version of [crate::tasks::reopen_task]
+pub fn reopen_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::tasks::reopen_task(config, task_id))
+}
+
+/// Blocking version of [crate::tasks::delete_task]
+pub fn delete_task(config: &TodoistConfig, task_id: String) -> Result<(), TodoistAPIError> {
+    super::block_on(crate::tasks::delete_task(config, task_id))
+}
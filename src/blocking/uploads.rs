@@ -0,0 +1,14 @@
+//! Blocking version of the Todoist Uploads API (<https://developer.todoist.com/api/v1/#tag/Uploads>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::comment::CommentAttachment;
+use crate::todoist_config::TodoistConfig;
+use crate::uploads::UploadSource;
+
+/// Blocking version of [crate::uploads::upload_file]
+pub fn upload_file(
+    config: &TodoistConfig,
+    source: UploadSource,
+) -> Result<CommentAttachment, TodoistAPIError> {
+    super::block_on(crate::uploads::upload_file(config, source))
+}
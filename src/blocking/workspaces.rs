@@ -0,0 +1,33 @@
+//! Blocking version of the Todoist Workspaces API (<https://developer.todoist.com/api/v1/#tag/Workspace>)
+
+use crate::internal::request::TodoistAPIError;
+use crate::model::project::Project;
+use crate::model::workspace::{Workspace, WorkspaceMember};
+use crate::todoist_config::TodoistConfig;
+
+/// Blocking version of [crate::workspaces::get_all_workspaces]
+pub fn get_all_workspaces(config: &TodoistConfig) -> Result<Vec<Workspace>, TodoistAPIError> {
+    super::block_on(crate::workspaces::get_all_workspaces(config))
+}
+
+/// Blocking version of [crate::workspaces::get_workspace_members]
+pub fn get_workspace_members(
+    config: &TodoistConfig,
+    workspace_id: String,
+) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+    super::block_on(crate::workspaces::get_workspace_members(
+        config,
+        workspace_id,
+    ))
+}
+
+/// Blocking version of [crate::workspaces::get_workspace_projects]
+pub fn get_workspace_projects(
+    config: &TodoistConfig,
+    workspace_id: String,
+) -> Result<Vec<Project>, TodoistAPIError> {
+    super::block_on(crate::workspaces::get_workspace_projects(
+        config,
+        workspace_id,
+    ))
+}
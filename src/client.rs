@@ -0,0 +1,886 @@
+//! An object-oriented handle to the Todoist API
+//!
+//! [TodoistClient] wraps a [TodoistConfig] and groups the API functions by resource:
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use todoist_rest_api::middleware::{RateLimiter, RetryPolicy};
+//! use todoist_rest_api::TodoistClient;
+//!
+//! let client = TodoistClient::from_token("0123456789abcdef")?
+//!     .with_retry_policy(RetryPolicy::default())
+//!     .with_rate_limiter(RateLimiter::default());
+//! for task in client.tasks().list().await? {
+//!     println!("{}", task.content);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use futures_util::Stream;
+use tokio::io::AsyncWrite;
+
+use crate::attachments::DownloadedFile;
+use crate::bulk::{NewTask, TaskUpdate};
+use crate::comments::TaskOrProjectID;
+use crate::internal::request::TodoistAPIError;
+use crate::label_tools::{LabelMergeReport, LabelRenameReport, OrphanLabels};
+use crate::middleware::{Middleware, RateLimiter, ResponseCache, RetryPolicy};
+use crate::model::collaborator::{Collaborator, CollaboratorRole};
+use crate::model::color::Color;
+use crate::model::comment::{Comment, CommentAttachment};
+use crate::model::label::PersonalLabel;
+use crate::model::project::{Project, ProjectViewStyle};
+use crate::model::reminder::{Reminder, ReminderType};
+use crate::model::section::Section;
+use crate::model::task::{Task, TaskDuration};
+use crate::model::workspace::{Workspace, WorkspaceMember};
+use crate::reorder::{OrderChange, ReorderTarget};
+use crate::secret::SecretString;
+use crate::todoist_config::{ApiVersion, TodoistConfig, TodoistConfigCreationErrors};
+use crate::uploads::UploadSource;
+
+/// A handle to the Todoist API
+///
+/// The [middleware](crate::middleware) of the wrapped [TodoistConfig] applies to every request,
+/// and cloning a [TodoistClient] is cheap.
+#[derive(Debug, Clone)]
+pub struct TodoistClient {
+    config: TodoistConfig,
+}
+
+impl TodoistClient {
+    /// Creates a new [TodoistClient] from an existing [TodoistConfig]
+    pub fn new(config: TodoistConfig) -> TodoistClient {
+        TodoistClient { config }
+    }
+
+    /// Creates a new [TodoistClient] using a Todoist API token
+    /// (see <https://developer.todoist.com/rest/v2/#authorization>)
+    pub fn from_token(
+        token: impl Into<SecretString>,
+    ) -> Result<TodoistClient, TodoistConfigCreationErrors> {
+        Ok(TodoistClient::new(TodoistConfig::new(token)?))
+    }
+
+    /// Get the [TodoistConfig] used by this client,
+    /// which can be passed to the functions in [crate::api](crate)
+    pub fn config(&self) -> &TodoistConfig {
+        &self.config
+    }
+
+    /// Sends requests to the given [ApiVersion] (defaults to [ApiVersion::V2])
+    pub fn with_api_version(self, api_version: ApiVersion) -> TodoistClient {
+        TodoistClient::new(self.config.with_api_version(api_version))
+    }
+
+    /// Retries failed requests according to the [RetryPolicy]
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> TodoistClient {
+        TodoistClient::new(self.config.with_retry_policy(retry_policy))
+    }
+
+    /// Delays requests to stay within the limit of the [RateLimiter]
+    pub fn with_rate_limiter(self, rate_limiter: RateLimiter) -> TodoistClient {
+        TodoistClient::new(self.config.with_rate_limiter(rate_limiter))
+    }
+
+    /// Reuses recent responses of `GET` requests stored in the [ResponseCache]
+    pub fn with_response_cache(self, response_cache: ResponseCache) -> TodoistClient {
+        TodoistClient::new(self.config.with_response_cache(response_cache))
+    }
+
+    /// Adds a [Middleware] that is called before each request is sent and after each response is received
+    pub fn with_middleware(self, middleware: impl Middleware + 'static) -> TodoistClient {
+        TodoistClient::new(self.config.with_middleware(middleware))
+    }
+
+    /// The [tasks](Task) API
+    pub fn tasks(&self) -> TasksApi<'_> {
+        TasksApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [projects](Project) API
+    pub fn projects(&self) -> ProjectsApi<'_> {
+        ProjectsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [sections](Section) API
+    pub fn sections(&self) -> SectionsApi<'_> {
+        SectionsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [labels](PersonalLabel) API
+    pub fn labels(&self) -> LabelsApi<'_> {
+        LabelsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [comments](Comment) API
+    pub fn comments(&self) -> CommentsApi<'_> {
+        CommentsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [reminders](Reminder) API
+    pub fn reminders(&self) -> RemindersApi<'_> {
+        RemindersApi {
+            config: &self.config,
+        }
+    }
+
+    /// The uploads API, for [attachments](CommentAttachment)
+    pub fn uploads(&self) -> UploadsApi<'_> {
+        UploadsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [attachment](CommentAttachment) downloads API
+    pub fn attachments(&self) -> AttachmentsApi<'_> {
+        AttachmentsApi {
+            config: &self.config,
+        }
+    }
+
+    /// The [workspaces](Workspace) API
+    pub fn workspaces(&self) -> WorkspacesApi<'_> {
+        WorkspacesApi {
+            config: &self.config,
+        }
+    }
+
+    /// The reordering API, for tasks, projects, sections and labels
+    pub fn ordering(&self) -> OrderingApi<'_> {
+        OrderingApi {
+            config: &self.config,
+        }
+    }
+}
+
+impl From<TodoistConfig> for TodoistClient {
+    fn from(value: TodoistConfig) -> Self {
+        TodoistClient::new(value)
+    }
+}
+
+/// The Todoist Tasks API (see [crate::tasks])
+#[derive(Debug, Clone, Copy)]
+pub struct TasksApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> TasksApi<'a> {
+    /// Get all active tasks (see [crate::tasks::get_active_tasks])
+    pub async fn list(&self) -> Result<Vec<Task>, TodoistAPIError> {
+        crate::tasks::get_active_tasks(self.config, None, None, None, None, None, None).await
+    }
+
+    /// Get all active tasks matching the given filters (see [crate::tasks::get_active_tasks])
+    pub async fn list_filtered(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> Result<Vec<Task>, TodoistAPIError> {
+        crate::tasks::get_active_tasks(
+            self.config,
+            project_id,
+            section_id,
+            label,
+            filter,
+            lang,
+            ids,
+        )
+        .await
+    }
+
+    /// Stream all active tasks matching the given filters (see [crate::tasks::stream_active_tasks])
+    pub fn stream(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> impl Stream<Item = Result<Task, TodoistAPIError>> + 'a {
+        crate::tasks::stream_active_tasks(
+            self.config,
+            project_id,
+            section_id,
+            label,
+            filter,
+            lang,
+            ids,
+        )
+    }
+
+    /// Get an active task by ID (see [crate::tasks::get_active_task])
+    pub async fn get(&self, task_id: String) -> Result<Task, TodoistAPIError> {
+        crate::tasks::get_active_task(self.config, task_id).await
+    }
+
+    /// Create a new task (see [crate::tasks::create_new_task])
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create(
+        &self,
+        content: String,
+        description: Option<String>,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        parent_id: Option<String>,
+        order: Option<u32>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        crate::tasks::create_new_task(
+            self.config,
+            content,
+            description,
+            project_id,
+            section_id,
+            parent_id,
+            order,
+            labels,
+            priority,
+            due_string,
+            due_date,
+            due_datetime,
+            due_lang,
+            assignee_id,
+            duration,
+        )
+        .await
+    }
+
+    /// Update a task (see [crate::tasks::update_task])
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update(
+        &self,
+        task_id: String,
+        content: Option<String>,
+        description: Option<String>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        crate::tasks::update_task(
+            self.config,
+            task_id,
+            content,
+            description,
+            labels,
+            priority,
+            due_string,
+            due_date,
+            due_datetime,
+            due_lang,
+            assignee_id,
+            duration,
+        )
+        .await
+    }
+
+    /// Close a task (see [crate::tasks::close_task])
+    pub async fn close(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::close_task(self.config, task_id).await
+    }
+
+    /// Reopen a task (see [crate::tasks::reopen_task])
+    pub async fn reopen(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::reopen_task(self.config, task_id).await
+    }
+
+    /// Delete a task (see [crate::tasks::delete_task])
+    pub async fn delete(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::delete_task(self.config, task_id).await
+    }
+
+    /// Close many tasks (see [crate::bulk::close_tasks])
+    pub async fn close_many(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        crate::bulk::close_tasks(self.config, task_ids, concurrency).await
+    }
+
+    /// Delete many tasks (see [crate::bulk::delete_tasks])
+    pub async fn delete_many(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        crate::bulk::delete_tasks(self.config, task_ids, concurrency).await
+    }
+
+    /// Create many tasks (see [crate::bulk::create_tasks])
+    pub async fn create_many(
+        &self,
+        tasks: Vec<NewTask>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::create_tasks(self.config, tasks, concurrency).await
+    }
+
+    /// Update many tasks (see [crate::bulk::update_tasks])
+    pub async fn update_many(
+        &self,
+        updates: Vec<TaskUpdate>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::update_tasks(self.config, updates, concurrency).await
+    }
+
+    /// Add a label to many tasks (see [crate::bulk::add_label_to_tasks])
+    pub async fn add_label_to_many(
+        &self,
+        tasks: Vec<Task>,
+        label: String,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::add_label_to_tasks(self.config, tasks, label, concurrency).await
+    }
+}
+
+/// The Todoist Projects API (see [crate::projects])
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> ProjectsApi<'a> {
+    /// Get all projects (see [crate::projects::get_all_projects])
+    pub async fn list(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::projects::get_all_projects(self.config).await
+    }
+
+    /// Stream all projects (see [crate::projects::stream_all_projects])
+    pub fn stream(&self) -> impl Stream<Item = Result<Project, TodoistAPIError>> + 'a {
+        crate::projects::stream_all_projects(self.config)
+    }
+
+    /// Get a project by ID (see [crate::projects::get_project])
+    pub async fn get(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::get_project(self.config, project_id).await
+    }
+
+    /// Create a new project (see [crate::projects::create_new_project])
+    pub async fn create(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        crate::projects::create_new_project(
+            self.config,
+            name,
+            parent_id,
+            color,
+            is_favorite,
+            view_style,
+        )
+        .await
+    }
+
+    /// Update a project (see [crate::projects::update_project])
+    pub async fn update(
+        &self,
+        project_id: String,
+        name: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        crate::projects::update_project(
+            self.config,
+            project_id,
+            name,
+            color,
+            is_favorite,
+            view_style,
+        )
+        .await
+    }
+
+    /// Delete a project (see [crate::projects::delete_project])
+    pub async fn delete(&self, project_id: String) -> Result<(), TodoistAPIError> {
+        crate::projects::delete_project(self.config, project_id).await
+    }
+
+    /// Get all collaborators of a shared project (see [crate::projects::get_all_collaborators])
+    pub async fn collaborators(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        crate::projects::get_all_collaborators(self.config, project_id).await
+    }
+
+    /// Get all collaborators of a project with their roles (see [crate::projects::get_collaborators_with_roles])
+    pub async fn collaborators_with_roles(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        crate::projects::get_collaborators_with_roles(self.config, project_id).await
+    }
+
+    /// Share a project by email (see [crate::projects::share_project])
+    pub async fn share(
+        &self,
+        project_id: String,
+        email: String,
+        role: Option<CollaboratorRole>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::share_project(self.config, project_id, email, role).await
+    }
+
+    /// Remove a collaborator from a project (see [crate::projects::delete_collaborator])
+    pub async fn delete_collaborator(
+        &self,
+        project_id: String,
+        email: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::delete_collaborator(self.config, project_id, email).await
+    }
+
+    /// Accept an invitation to a project (see [crate::projects::accept_invitation])
+    pub async fn accept_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::accept_invitation(self.config, invitation_id, invitation_secret).await
+    }
+
+    /// Reject an invitation to a project (see [crate::projects::reject_invitation])
+    pub async fn reject_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::reject_invitation(self.config, invitation_id, invitation_secret).await
+    }
+
+    /// Archive a project (see [crate::projects::archive_project])
+    pub async fn archive(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::archive_project(self.config, project_id).await
+    }
+
+    /// Unarchive a project (see [crate::projects::unarchive_project])
+    pub async fn unarchive(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::unarchive_project(self.config, project_id).await
+    }
+
+    /// Get all archived projects (see [crate::projects::get_archived_projects])
+    pub async fn list_archived(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::projects::get_archived_projects(self.config).await
+    }
+
+    /// Stream all archived projects (see [crate::projects::stream_archived_projects])
+    pub fn stream_archived(&self) -> impl Stream<Item = Result<Project, TodoistAPIError>> + 'a {
+        crate::projects::stream_archived_projects(self.config)
+    }
+}
+
+/// The Todoist Sections API (see [crate::sections])
+#[derive(Debug, Clone, Copy)]
+pub struct SectionsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl SectionsApi<'_> {
+    /// Get all sections, optionally of a single project (see [crate::sections::get_all_sections])
+    pub async fn list(&self, project_id: Option<String>) -> Result<Vec<Section>, TodoistAPIError> {
+        crate::sections::get_all_sections(self.config, project_id).await
+    }
+
+    /// Get a section by ID (see [crate::sections::get_section])
+    pub async fn get(&self, section_id: String) -> Result<Section, TodoistAPIError> {
+        crate::sections::get_section(self.config, section_id).await
+    }
+
+    /// Create a new section (see [crate::sections::create_new_section])
+    pub async fn create(
+        &self,
+        project_id: String,
+        name: String,
+        order: Option<u32>,
+    ) -> Result<Section, TodoistAPIError> {
+        crate::sections::create_new_section(self.config, project_id, name, order).await
+    }
+
+    /// Rename a section (see [crate::sections::update_section])
+    pub async fn update(
+        &self,
+        section_id: String,
+        name: String,
+    ) -> Result<Section, TodoistAPIError> {
+        crate::sections::update_section(self.config, section_id, name).await
+    }
+
+    /// Delete a section (see [crate::sections::delete_section])
+    pub async fn delete(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::delete_section(self.config, section_id).await
+    }
+
+    /// Archive a section (see [crate::sections::archive_section])
+    pub async fn archive(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::archive_section(self.config, section_id).await
+    }
+
+    /// Unarchive a section (see [crate::sections::unarchive_section])
+    pub async fn unarchive(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::unarchive_section(self.config, section_id).await
+    }
+
+    /// Move a section to another project (see [crate::sections::move_section])
+    pub async fn move_to_project(
+        &self,
+        section_id: String,
+        project_id: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::sections::move_section(self.config, section_id, project_id).await
+    }
+
+    /// Reorder the sections of a project (see [crate::sections::reorder_sections])
+    pub async fn reorder(&self, section_ids: Vec<String>) -> Result<(), TodoistAPIError> {
+        crate::sections::reorder_sections(self.config, section_ids).await
+    }
+}
+
+/// The Todoist Labels API (see [crate::labels])
+#[derive(Debug, Clone, Copy)]
+pub struct LabelsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl LabelsApi<'_> {
+    /// Get all personal labels (see [crate::labels::get_all_personal_labels])
+    pub async fn list(&self) -> Result<Vec<PersonalLabel>, TodoistAPIError> {
+        crate::labels::get_all_personal_labels(self.config).await
+    }
+
+    /// Get a personal label by ID (see [crate::labels::get_personal_label])
+    pub async fn get(&self, label_id: String) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::get_personal_label(self.config, label_id).await
+    }
+
+    /// Create a new personal label (see [crate::labels::create_new_personal_label])
+    pub async fn create(
+        &self,
+        name: String,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::create_new_personal_label(self.config, name, order, color, is_favorite).await
+    }
+
+    /// Update a personal label (see [crate::labels::update_personal_label])
+    pub async fn update(
+        &self,
+        label_id: String,
+        name: Option<String>,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::update_personal_label(self.config, label_id, name, order, color, is_favorite)
+            .await
+    }
+
+    /// Delete a personal label (see [crate::labels::delete_personal_label])
+    pub async fn delete(&self, label_id: String) -> Result<(), TodoistAPIError> {
+        crate::labels::delete_personal_label(self.config, label_id).await
+    }
+
+    /// Get the names of all shared labels (see [crate::labels::get_all_shared_labels])
+    pub async fn list_shared(
+        &self,
+        omit_personal: Option<bool>,
+    ) -> Result<Vec<String>, TodoistAPIError> {
+        crate::labels::get_all_shared_labels(self.config, omit_personal).await
+    }
+
+    /// Rename all instances of a shared label (see [crate::labels::rename_shared_labels])
+    pub async fn rename_shared(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::labels::rename_shared_labels(self.config, name, new_name).await
+    }
+
+    /// Remove all instances of a shared label (see [crate::labels::remove_shared_labels])
+    pub async fn remove_shared(&self, name: String) -> Result<(), TodoistAPIError> {
+        crate::labels::remove_shared_labels(self.config, name).await
+    }
+
+    /// Replace a label with another one on every active task (see [crate::label_tools::merge_labels])
+    pub async fn merge(
+        &self,
+        from: String,
+        into: String,
+        concurrency: usize,
+    ) -> Result<LabelMergeReport, TodoistAPIError> {
+        crate::label_tools::merge_labels(self.config, from, into, concurrency).await
+    }
+
+    /// Rename a personal and shared label (see [crate::label_tools::rename_label_everywhere])
+    pub async fn rename_everywhere(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<LabelRenameReport, TodoistAPIError> {
+        crate::label_tools::rename_label_everywhere(self.config, name, new_name).await
+    }
+
+    /// Find the labels without a personal label or without tasks (see [crate::label_tools::find_orphan_labels])
+    pub async fn find_orphans(&self) -> Result<OrphanLabels, TodoistAPIError> {
+        crate::label_tools::find_orphan_labels(self.config).await
+    }
+}
+
+/// The Todoist Comments API (see [crate::comments])
+#[derive(Debug, Clone, Copy)]
+pub struct CommentsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> CommentsApi<'a> {
+    /// Get all comments of a task or project (see [crate::comments::get_all_comments])
+    pub async fn list(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> Result<Vec<Comment>, TodoistAPIError> {
+        crate::comments::get_all_comments(self.config, task_or_project_id).await
+    }
+
+    /// Stream all comments of a task or project (see [crate::comments::stream_all_comments])
+    pub fn stream(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> impl Stream<Item = Result<Comment, TodoistAPIError>> + 'a {
+        crate::comments::stream_all_comments(self.config, task_or_project_id)
+    }
+
+    /// Get a comment by ID (see [crate::comments::get_comment])
+    pub async fn get(&self, comment_id: String) -> Result<Comment, TodoistAPIError> {
+        crate::comments::get_comment(self.config, comment_id).await
+    }
+
+    /// Create a new comment on a task or project (see [crate::comments::create_new_comment])
+    pub async fn create(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+        content: String,
+        attachment: Option<CommentAttachment>,
+        uids_to_notify: Option<Vec<String>>,
+    ) -> Result<Comment, TodoistAPIError> {
+        crate::comments::create_new_comment(
+            self.config,
+            task_or_project_id,
+            content,
+            attachment,
+            uids_to_notify,
+        )
+        .await
+    }
+
+    /// Update a comment (see [crate::comments::update_comment])
+    pub async fn update(
+        &self,
+        comment_id: String,
+        content: String,
+    ) -> Result<Comment, TodoistAPIError> {
+        crate::comments::update_comment(self.config, comment_id, content).await
+    }
+
+    /// Delete a comment (see [crate::comments::delete_comment])
+    pub async fn delete(&self, comment_id: String) -> Result<(), TodoistAPIError> {
+        crate::comments::delete_comment(self.config, comment_id).await
+    }
+}
+
+/// The Todoist Reminders API (see [crate::reminders])
+#[derive(Debug, Clone, Copy)]
+pub struct RemindersApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> RemindersApi<'a> {
+    /// Get all reminders (see [crate::reminders::get_all_reminders])
+    pub async fn list(&self) -> Result<Vec<Reminder>, TodoistAPIError> {
+        crate::reminders::get_all_reminders(self.config).await
+    }
+
+    /// Get all reminders of a task (see [crate::reminders::get_task_reminders])
+    pub async fn list_for_task(&self, task_id: String) -> Result<Vec<Reminder>, TodoistAPIError> {
+        crate::reminders::get_task_reminders(self.config, task_id).await
+    }
+
+    /// Get a reminder by ID (see [crate::reminders::get_reminder])
+    pub async fn get(&self, reminder_id: String) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::get_reminder(self.config, reminder_id).await
+    }
+
+    /// Create a new reminder for a task (see [crate::reminders::create_new_reminder])
+    pub async fn create(
+        &self,
+        task_id: String,
+        reminder_type: ReminderType,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::create_new_reminder(self.config, task_id, reminder_type, notify_uid).await
+    }
+
+    /// Update a reminder (see [crate::reminders::update_reminder])
+    pub async fn update(
+        &self,
+        reminder_id: String,
+        reminder_type: Option<ReminderType>,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::update_reminder(self.config, reminder_id, reminder_type, notify_uid).await
+    }
+
+    /// Delete a reminder (see [crate::reminders::delete_reminder])
+    pub async fn delete(&self, reminder_id: String) -> Result<(), TodoistAPIError> {
+        crate::reminders::delete_reminder(self.config, reminder_id).await
+    }
+}
+
+/// The Todoist Uploads API (see [crate::uploads])
+#[derive(Debug, Clone, Copy)]
+pub struct UploadsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> UploadsApi<'a> {
+    /// Upload a file to attach to a comment (see [crate::uploads::upload_file])
+    pub async fn upload(&self, source: UploadSource) -> Result<CommentAttachment, TodoistAPIError> {
+        crate::uploads::upload_file(self.config, source).await
+    }
+}
+
+/// The downloads of comment attachments (see [crate::attachments])
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentsApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> AttachmentsApi<'a> {
+    /// Download an attachment into a writer (see [crate::attachments::download_attachment])
+    pub async fn download<W: AsyncWrite + Unpin + ?Sized>(
+        &self,
+        attachment: &CommentAttachment,
+        writer: &mut W,
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        crate::attachments::download_attachment(self.config, attachment, writer).await
+    }
+
+    /// Download an attachment to a file (see [crate::attachments::download_attachment_to_path])
+    pub async fn download_to_path(
+        &self,
+        attachment: &CommentAttachment,
+        path: &Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        crate::attachments::download_attachment_to_path(
+            self.config,
+            attachment,
+            path,
+            expected_sha256,
+        )
+        .await
+    }
+
+    /// Download an attachment into a cache directory
+    /// (see [crate::attachments::download_attachment_cached])
+    pub async fn download_cached(
+        &self,
+        attachment: &CommentAttachment,
+        cache_directory: &Path,
+    ) -> Result<PathBuf, TodoistAPIError> {
+        crate::attachments::download_attachment_cached(self.config, attachment, cache_directory)
+            .await
+    }
+}
+
+/// The Todoist Workspaces API (see [crate::workspaces])
+#[derive(Debug, Clone, Copy)]
+pub struct WorkspacesApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> WorkspacesApi<'a> {
+    /// Get all workspaces (see [crate::workspaces::get_all_workspaces])
+    pub async fn list(&self) -> Result<Vec<Workspace>, TodoistAPIError> {
+        crate::workspaces::get_all_workspaces(self.config).await
+    }
+
+    /// Get all members of a workspace (see [crate::workspaces::get_workspace_members])
+    pub async fn members(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+        crate::workspaces::get_workspace_members(self.config, workspace_id).await
+    }
+
+    /// Get all projects of a workspace (see [crate::workspaces::get_workspace_projects])
+    pub async fn projects(&self, workspace_id: String) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::workspaces::get_workspace_projects(self.config, workspace_id).await
+    }
+}
+
+/// The Todoist reordering API (see [crate::reorder])
+#[derive(Debug, Clone, Copy)]
+pub struct OrderingApi<'a> {
+    config: &'a TodoistConfig,
+}
+
+impl<'a> OrderingApi<'a> {
+    /// Reorder resources with the same parent (see [crate::reorder::reorder])
+    pub async fn reorder(
+        &self,
+        target: ReorderTarget,
+        ids: Vec<String>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::reorder::reorder(self.config, target, ids).await
+    }
+
+    /// Apply order changes (see [crate::reorder::apply_order_changes])
+    pub async fn apply(
+        &self,
+        target: ReorderTarget,
+        changes: Vec<OrderChange>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::reorder::apply_order_changes(self.config, target, changes).await
+    }
+}
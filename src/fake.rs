@@ -0,0 +1,979 @@
+//! A programmable fake implementation of [TodoistApi] for tests
+//!
+//! ```
+//! # async fn run() {
+//! use todoist_rest_api::fake::FakeTodoistApi;
+//! use todoist_rest_api::model::project::Project;
+//! use todoist_rest_api::TodoistApi;
+//!
+//! let api = FakeTodoistApi::new();
+//! api.push_response("get_all_projects", Ok(Vec::<Project>::new()));
+//! assert!(api.get_all_projects().await.unwrap().is_empty());
+//! assert_eq!(api.get_calls()[0].operation, "get_all_projects");
+//! # }
+//! ```
+
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream};
+use futures_util::StreamExt;
+use tokio::io::AsyncWrite;
+
+use crate::attachments::DownloadedFile;
+use crate::bulk::{NewTask, TaskUpdate};
+use crate::comments::TaskOrProjectID;
+use crate::internal::request::TodoistAPIError;
+use crate::label_tools::{LabelMergeReport, LabelRenameReport, OrphanLabels};
+use crate::model::collaborator::{Collaborator, CollaboratorRole};
+use crate::model::color::Color;
+use crate::model::comment::{Comment, CommentAttachment};
+use crate::model::label::PersonalLabel;
+use crate::model::project::{Project, ProjectViewStyle};
+use crate::model::reminder::{Reminder, ReminderType};
+use crate::model::section::Section;
+use crate::model::task::{Task, TaskDuration};
+use crate::model::workspace::{Workspace, WorkspaceMember};
+use crate::reorder::{OrderChange, ReorderTarget};
+use crate::todoist_api::TodoistApi;
+use crate::uploads::UploadSource;
+
+type ScriptedResponses = HashMap<&'static str, VecDeque<Box<dyn Any + Send>>>;
+
+/// A fake [TodoistApi] that records every call and returns scripted responses
+///
+/// Responses are scripted per operation (the name of the [TodoistApi] method)
+/// with [FakeTodoistApi::push_response] and returned in the order that they were pushed.
+/// Calling an operation without a scripted response panics.
+#[derive(Default)]
+pub struct FakeTodoistApi {
+    calls: Mutex<Vec<RecordedCall>>,
+    responses: Mutex<ScriptedResponses>,
+}
+
+/// A call made to a [FakeTodoistApi]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedCall {
+    /// The name of the operation (ex: `get_all_projects`)
+    pub operation: &'static str,
+    /// The arguments of the call (excluding `self`), formatted with [Debug]
+    pub arguments: Vec<String>,
+}
+
+impl FakeTodoistApi {
+    /// Creates a new [FakeTodoistApi] without any scripted responses
+    pub fn new() -> FakeTodoistApi {
+        FakeTodoistApi::default()
+    }
+
+    /// Adds a response (or error) that is returned by the next call to `operation`
+    ///
+    /// `T` must be the success type returned by the operation (ex: `Vec<Project>` for `get_all_projects`).
+    /// Streaming operations are scripted with all of their items (ex: `Vec<Project>` for `stream_all_projects`),
+    /// and an error is returned as the only item of the stream.
+    /// Bulk operations are scripted with one result per item
+    /// (ex: `Vec<Result<(), TodoistAPIError>>` for `close_tasks`),
+    /// and an error is returned as the only result.
+    pub fn push_response<T: Send + 'static>(
+        &self,
+        operation: &'static str,
+        response: Result<T, TodoistAPIError>,
+    ) -> &FakeTodoistApi {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(operation)
+            .or_default()
+            .push_back(Box::new(response));
+        self
+    }
+
+    /// Get all calls made so far, in order
+    pub fn get_calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Get all calls made so far to `operation`, in order
+    pub fn get_calls_to(&self, operation: &str) -> Vec<RecordedCall> {
+        self.get_calls()
+            .into_iter()
+            .filter(|call| call.operation == operation)
+            .collect()
+    }
+
+    fn record_call(&self, operation: &'static str, arguments: Vec<String>) {
+        self.calls.lock().unwrap().push(RecordedCall {
+            operation,
+            arguments,
+        });
+    }
+
+    fn next_response<T: 'static>(&self, operation: &'static str) -> Result<T, TodoistAPIError> {
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .get_mut(operation)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_else(|| panic!("No response was scripted for '{}'", operation));
+        *response
+            .downcast::<Result<T, TodoistAPIError>>()
+            .unwrap_or_else(|_| {
+                panic!(
+                    "The response scripted for '{}' is not a Result<{}, TodoistAPIError>",
+                    operation,
+                    std::any::type_name::<T>()
+                )
+            })
+    }
+
+    fn next_stream<T: Send + 'static>(
+        &self,
+        operation: &'static str,
+    ) -> BoxStream<'static, Result<T, TodoistAPIError>> {
+        match self.next_response::<Vec<T>>(operation) {
+            Ok(items) => stream::iter(items.into_iter().map(Ok)).boxed(),
+            Err(error) => stream::once(async { Err(error) }).boxed(),
+        }
+    }
+
+    fn next_bulk_response<T: 'static>(
+        &self,
+        operation: &'static str,
+    ) -> Vec<Result<T, TodoistAPIError>> {
+        self.next_response(operation)
+            .unwrap_or_else(|error| vec![Err(error)])
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[async_trait]
+impl TodoistApi for FakeTodoistApi {
+    async fn get_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> Result<Vec<Task>, TodoistAPIError> {
+        self.record_call(
+            "get_active_tasks",
+            vec![
+                format!("{:?}", project_id),
+                format!("{:?}", section_id),
+                format!("{:?}", label),
+                format!("{:?}", filter),
+                format!("{:?}", lang),
+                format!("{:?}", ids),
+            ],
+        );
+        self.next_response("get_active_tasks")
+    }
+
+    fn stream_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> BoxStream<'_, Result<Task, TodoistAPIError>> {
+        self.record_call(
+            "stream_active_tasks",
+            vec![
+                format!("{:?}", project_id),
+                format!("{:?}", section_id),
+                format!("{:?}", label),
+                format!("{:?}", filter),
+                format!("{:?}", lang),
+                format!("{:?}", ids),
+            ],
+        );
+        self.next_stream("stream_active_tasks")
+    }
+
+    async fn create_new_task(
+        &self,
+        content: String,
+        description: Option<String>,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        parent_id: Option<String>,
+        order: Option<u32>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        self.record_call(
+            "create_new_task",
+            vec![
+                format!("{:?}", content),
+                format!("{:?}", description),
+                format!("{:?}", project_id),
+                format!("{:?}", section_id),
+                format!("{:?}", parent_id),
+                format!("{:?}", order),
+                format!("{:?}", labels),
+                format!("{:?}", priority),
+                format!("{:?}", due_string),
+                format!("{:?}", due_date),
+                format!("{:?}", due_datetime),
+                format!("{:?}", due_lang),
+                format!("{:?}", assignee_id),
+                format!("{:?}", duration),
+            ],
+        );
+        self.next_response("create_new_task")
+    }
+
+    async fn get_active_task(&self, task_id: String) -> Result<Task, TodoistAPIError> {
+        self.record_call("get_active_task", vec![format!("{:?}", task_id)]);
+        self.next_response("get_active_task")
+    }
+
+    async fn update_task(
+        &self,
+        task_id: String,
+        content: Option<String>,
+        description: Option<String>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        self.record_call(
+            "update_task",
+            vec![
+                format!("{:?}", task_id),
+                format!("{:?}", content),
+                format!("{:?}", description),
+                format!("{:?}", labels),
+                format!("{:?}", priority),
+                format!("{:?}", due_string),
+                format!("{:?}", due_date),
+                format!("{:?}", due_datetime),
+                format!("{:?}", due_lang),
+                format!("{:?}", assignee_id),
+                format!("{:?}", duration),
+            ],
+        );
+        self.next_response("update_task")
+    }
+
+    async fn close_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("close_task", vec![format!("{:?}", task_id)]);
+        self.next_response("close_task")
+    }
+
+    async fn reopen_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("reopen_task", vec![format!("{:?}", task_id)]);
+        self.next_response("reopen_task")
+    }
+
+    async fn delete_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_task", vec![format!("{:?}", task_id)]);
+        self.next_response("delete_task")
+    }
+
+    async fn get_all_projects(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        self.record_call("get_all_projects", vec![]);
+        self.next_response("get_all_projects")
+    }
+
+    fn stream_all_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>> {
+        self.record_call("stream_all_projects", vec![]);
+        self.next_stream("stream_all_projects")
+    }
+
+    async fn create_new_project(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        self.record_call(
+            "create_new_project",
+            vec![
+                format!("{:?}", name),
+                format!("{:?}", parent_id),
+                format!("{:?}", color),
+                format!("{:?}", is_favorite),
+                format!("{:?}", view_style),
+            ],
+        );
+        self.next_response("create_new_project")
+    }
+
+    async fn get_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        self.record_call("get_project", vec![format!("{:?}", project_id)]);
+        self.next_response("get_project")
+    }
+
+    async fn update_project(
+        &self,
+        project_id: String,
+        name: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        self.record_call(
+            "update_project",
+            vec![
+                format!("{:?}", project_id),
+                format!("{:?}", name),
+                format!("{:?}", color),
+                format!("{:?}", is_favorite),
+                format!("{:?}", view_style),
+            ],
+        );
+        self.next_response("update_project")
+    }
+
+    async fn delete_project(&self, project_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_project", vec![format!("{:?}", project_id)]);
+        self.next_response("delete_project")
+    }
+
+    async fn get_all_collaborators(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        self.record_call("get_all_collaborators", vec![format!("{:?}", project_id)]);
+        self.next_response("get_all_collaborators")
+    }
+
+    async fn get_collaborators_with_roles(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        self.record_call(
+            "get_collaborators_with_roles",
+            vec![format!("{:?}", project_id)],
+        );
+        self.next_response("get_collaborators_with_roles")
+    }
+
+    async fn share_project(
+        &self,
+        project_id: String,
+        email: String,
+        role: Option<CollaboratorRole>,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "share_project",
+            vec![
+                format!("{:?}", project_id),
+                format!("{:?}", email),
+                format!("{:?}", role),
+            ],
+        );
+        self.next_response("share_project")
+    }
+
+    async fn delete_collaborator(
+        &self,
+        project_id: String,
+        email: String,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "delete_collaborator",
+            vec![format!("{:?}", project_id), format!("{:?}", email)],
+        );
+        self.next_response("delete_collaborator")
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "accept_invitation",
+            vec![
+                format!("{:?}", invitation_id),
+                format!("{:?}", invitation_secret),
+            ],
+        );
+        self.next_response("accept_invitation")
+    }
+
+    async fn reject_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "reject_invitation",
+            vec![
+                format!("{:?}", invitation_id),
+                format!("{:?}", invitation_secret),
+            ],
+        );
+        self.next_response("reject_invitation")
+    }
+
+    async fn archive_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        self.record_call("archive_project", vec![format!("{:?}", project_id)]);
+        self.next_response("archive_project")
+    }
+
+    async fn unarchive_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        self.record_call("unarchive_project", vec![format!("{:?}", project_id)]);
+        self.next_response("unarchive_project")
+    }
+
+    async fn get_archived_projects(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        self.record_call("get_archived_projects", vec![]);
+        self.next_response("get_archived_projects")
+    }
+
+    fn stream_archived_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>> {
+        self.record_call("stream_archived_projects", vec![]);
+        self.next_stream("stream_archived_projects")
+    }
+
+    async fn get_all_sections(
+        &self,
+        project_id: Option<String>,
+    ) -> Result<Vec<Section>, TodoistAPIError> {
+        self.record_call("get_all_sections", vec![format!("{:?}", project_id)]);
+        self.next_response("get_all_sections")
+    }
+
+    async fn create_new_section(
+        &self,
+        project_id: String,
+        name: String,
+        order: Option<u32>,
+    ) -> Result<Section, TodoistAPIError> {
+        self.record_call(
+            "create_new_section",
+            vec![
+                format!("{:?}", project_id),
+                format!("{:?}", name),
+                format!("{:?}", order),
+            ],
+        );
+        self.next_response("create_new_section")
+    }
+
+    async fn get_section(&self, section_id: String) -> Result<Section, TodoistAPIError> {
+        self.record_call("get_section", vec![format!("{:?}", section_id)]);
+        self.next_response("get_section")
+    }
+
+    async fn update_section(
+        &self,
+        section_id: String,
+        name: String,
+    ) -> Result<Section, TodoistAPIError> {
+        self.record_call(
+            "update_section",
+            vec![format!("{:?}", section_id), format!("{:?}", name)],
+        );
+        self.next_response("update_section")
+    }
+
+    async fn delete_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_section", vec![format!("{:?}", section_id)]);
+        self.next_response("delete_section")
+    }
+
+    async fn archive_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("archive_section", vec![format!("{:?}", section_id)]);
+        self.next_response("archive_section")
+    }
+
+    async fn unarchive_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("unarchive_section", vec![format!("{:?}", section_id)]);
+        self.next_response("unarchive_section")
+    }
+
+    async fn move_section(
+        &self,
+        section_id: String,
+        project_id: String,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "move_section",
+            vec![format!("{:?}", section_id), format!("{:?}", project_id)],
+        );
+        self.next_response("move_section")
+    }
+
+    async fn reorder_sections(&self, section_ids: Vec<String>) -> Result<(), TodoistAPIError> {
+        self.record_call("reorder_sections", vec![format!("{:?}", section_ids)]);
+        self.next_response("reorder_sections")
+    }
+
+    async fn get_all_personal_labels(&self) -> Result<Vec<PersonalLabel>, TodoistAPIError> {
+        self.record_call("get_all_personal_labels", vec![]);
+        self.next_response("get_all_personal_labels")
+    }
+
+    async fn create_new_personal_label(
+        &self,
+        name: String,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        self.record_call(
+            "create_new_personal_label",
+            vec![
+                format!("{:?}", name),
+                format!("{:?}", order),
+                format!("{:?}", color),
+                format!("{:?}", is_favorite),
+            ],
+        );
+        self.next_response("create_new_personal_label")
+    }
+
+    async fn get_personal_label(&self, label_id: String) -> Result<PersonalLabel, TodoistAPIError> {
+        self.record_call("get_personal_label", vec![format!("{:?}", label_id)]);
+        self.next_response("get_personal_label")
+    }
+
+    async fn update_personal_label(
+        &self,
+        label_id: String,
+        name: Option<String>,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        self.record_call(
+            "update_personal_label",
+            vec![
+                format!("{:?}", label_id),
+                format!("{:?}", name),
+                format!("{:?}", order),
+                format!("{:?}", color),
+                format!("{:?}", is_favorite),
+            ],
+        );
+        self.next_response("update_personal_label")
+    }
+
+    async fn delete_personal_label(&self, label_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_personal_label", vec![format!("{:?}", label_id)]);
+        self.next_response("delete_personal_label")
+    }
+
+    async fn get_all_shared_labels(
+        &self,
+        omit_personal: Option<bool>,
+    ) -> Result<Vec<String>, TodoistAPIError> {
+        self.record_call(
+            "get_all_shared_labels",
+            vec![format!("{:?}", omit_personal)],
+        );
+        self.next_response("get_all_shared_labels")
+    }
+
+    async fn rename_shared_labels(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "rename_shared_labels",
+            vec![format!("{:?}", name), format!("{:?}", new_name)],
+        );
+        self.next_response("rename_shared_labels")
+    }
+
+    async fn remove_shared_labels(&self, name: String) -> Result<(), TodoistAPIError> {
+        self.record_call("remove_shared_labels", vec![format!("{:?}", name)]);
+        self.next_response("remove_shared_labels")
+    }
+
+    async fn get_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> Result<Vec<Comment>, TodoistAPIError> {
+        self.record_call(
+            "get_all_comments",
+            vec![format!("{:?}", task_or_project_id)],
+        );
+        self.next_response("get_all_comments")
+    }
+
+    fn stream_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> BoxStream<'_, Result<Comment, TodoistAPIError>> {
+        self.record_call(
+            "stream_all_comments",
+            vec![format!("{:?}", task_or_project_id)],
+        );
+        self.next_stream("stream_all_comments")
+    }
+
+    async fn create_new_comment(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+        content: String,
+        attachment: Option<CommentAttachment>,
+        uids_to_notify: Option<Vec<String>>,
+    ) -> Result<Comment, TodoistAPIError> {
+        self.record_call(
+            "create_new_comment",
+            vec![
+                format!("{:?}", task_or_project_id),
+                format!("{:?}", content),
+                format!("{:?}", attachment),
+                format!("{:?}", uids_to_notify),
+            ],
+        );
+        self.next_response("create_new_comment")
+    }
+
+    async fn get_comment(&self, comment_id: String) -> Result<Comment, TodoistAPIError> {
+        self.record_call("get_comment", vec![format!("{:?}", comment_id)]);
+        self.next_response("get_comment")
+    }
+
+    async fn update_comment(
+        &self,
+        comment_id: String,
+        content: String,
+    ) -> Result<Comment, TodoistAPIError> {
+        self.record_call(
+            "update_comment",
+            vec![format!("{:?}", comment_id), format!("{:?}", content)],
+        );
+        self.next_response("update_comment")
+    }
+
+    async fn delete_comment(&self, comment_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_comment", vec![format!("{:?}", comment_id)]);
+        self.next_response("delete_comment")
+    }
+
+    async fn get_all_reminders(&self) -> Result<Vec<Reminder>, TodoistAPIError> {
+        self.record_call("get_all_reminders", vec![]);
+        self.next_response("get_all_reminders")
+    }
+
+    async fn get_task_reminders(&self, task_id: String) -> Result<Vec<Reminder>, TodoistAPIError> {
+        self.record_call("get_task_reminders", vec![format!("{:?}", task_id)]);
+        self.next_response("get_task_reminders")
+    }
+
+    async fn get_reminder(&self, reminder_id: String) -> Result<Reminder, TodoistAPIError> {
+        self.record_call("get_reminder", vec![format!("{:?}", reminder_id)]);
+        self.next_response("get_reminder")
+    }
+
+    async fn create_new_reminder(
+        &self,
+        task_id: String,
+        reminder_type: ReminderType,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        self.record_call(
+            "create_new_reminder",
+            vec![
+                format!("{:?}", task_id),
+                format!("{:?}", reminder_type),
+                format!("{:?}", notify_uid),
+            ],
+        );
+        self.next_response("create_new_reminder")
+    }
+
+    async fn update_reminder(
+        &self,
+        reminder_id: String,
+        reminder_type: Option<ReminderType>,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        self.record_call(
+            "update_reminder",
+            vec![
+                format!("{:?}", reminder_id),
+                format!("{:?}", reminder_type),
+                format!("{:?}", notify_uid),
+            ],
+        );
+        self.next_response("update_reminder")
+    }
+
+    async fn delete_reminder(&self, reminder_id: String) -> Result<(), TodoistAPIError> {
+        self.record_call("delete_reminder", vec![format!("{:?}", reminder_id)]);
+        self.next_response("delete_reminder")
+    }
+
+    async fn upload_file(
+        &self,
+        source: UploadSource,
+    ) -> Result<CommentAttachment, TodoistAPIError> {
+        self.record_call("upload_file", vec![format!("{:?}", source)]);
+        self.next_response("upload_file")
+    }
+
+    async fn download_attachment(
+        &self,
+        attachment: &CommentAttachment,
+        _writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        self.record_call("download_attachment", vec![format!("{:?}", attachment)]);
+        self.next_response("download_attachment")
+    }
+
+    async fn download_attachment_to_path(
+        &self,
+        attachment: &CommentAttachment,
+        path: &Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        self.record_call(
+            "download_attachment_to_path",
+            vec![
+                format!("{:?}", attachment),
+                format!("{:?}", path),
+                format!("{:?}", expected_sha256),
+            ],
+        );
+        self.next_response("download_attachment_to_path")
+    }
+
+    async fn download_attachment_cached(
+        &self,
+        attachment: &CommentAttachment,
+        cache_directory: &Path,
+    ) -> Result<PathBuf, TodoistAPIError> {
+        self.record_call(
+            "download_attachment_cached",
+            vec![
+                format!("{:?}", attachment),
+                format!("{:?}", cache_directory),
+            ],
+        );
+        self.next_response("download_attachment_cached")
+    }
+
+    async fn get_all_workspaces(&self) -> Result<Vec<Workspace>, TodoistAPIError> {
+        self.record_call("get_all_workspaces", vec![]);
+        self.next_response("get_all_workspaces")
+    }
+
+    async fn get_workspace_members(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+        self.record_call("get_workspace_members", vec![format!("{:?}", workspace_id)]);
+        self.next_response("get_workspace_members")
+    }
+
+    async fn get_workspace_projects(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<Project>, TodoistAPIError> {
+        self.record_call(
+            "get_workspace_projects",
+            vec![format!("{:?}", workspace_id)],
+        );
+        self.next_response("get_workspace_projects")
+    }
+
+    async fn reorder(
+        &self,
+        target: ReorderTarget,
+        ids: Vec<String>,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "reorder",
+            vec![format!("{:?}", target), format!("{:?}", ids)],
+        );
+        self.next_response("reorder")
+    }
+
+    async fn apply_order_changes(
+        &self,
+        target: ReorderTarget,
+        changes: Vec<OrderChange>,
+    ) -> Result<(), TodoistAPIError> {
+        self.record_call(
+            "apply_order_changes",
+            vec![format!("{:?}", target), format!("{:?}", changes)],
+        );
+        self.next_response("apply_order_changes")
+    }
+
+    async fn close_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        self.record_call(
+            "close_tasks",
+            vec![format!("{:?}", task_ids), format!("{:?}", concurrency)],
+        );
+        self.next_bulk_response("close_tasks")
+    }
+
+    async fn delete_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        self.record_call(
+            "delete_tasks",
+            vec![format!("{:?}", task_ids), format!("{:?}", concurrency)],
+        );
+        self.next_bulk_response("delete_tasks")
+    }
+
+    async fn create_tasks(
+        &self,
+        tasks: Vec<NewTask>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        self.record_call(
+            "create_tasks",
+            vec![format!("{:?}", tasks), format!("{:?}", concurrency)],
+        );
+        self.next_bulk_response("create_tasks")
+    }
+
+    async fn update_tasks(
+        &self,
+        updates: Vec<TaskUpdate>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        self.record_call(
+            "update_tasks",
+            vec![format!("{:?}", updates), format!("{:?}", concurrency)],
+        );
+        self.next_bulk_response("update_tasks")
+    }
+
+    async fn add_label_to_tasks(
+        &self,
+        tasks: Vec<Task>,
+        label: String,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        self.record_call(
+            "add_label_to_tasks",
+            vec![
+                format!("{:?}", tasks),
+                format!("{:?}", label),
+                format!("{:?}", concurrency),
+            ],
+        );
+        self.next_bulk_response("add_label_to_tasks")
+    }
+
+    async fn merge_labels(
+        &self,
+        from: String,
+        into: String,
+        concurrency: usize,
+    ) -> Result<LabelMergeReport, TodoistAPIError> {
+        self.record_call(
+            "merge_labels",
+            vec![
+                format!("{:?}", from),
+                format!("{:?}", into),
+                format!("{:?}", concurrency),
+            ],
+        );
+        self.next_response("merge_labels")
+    }
+
+    async fn rename_label_everywhere(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<LabelRenameReport, TodoistAPIError> {
+        self.record_call(
+            "rename_label_everywhere",
+            vec![format!("{:?}", name), format!("{:?}", new_name)],
+        );
+        self.next_response("rename_label_everywhere")
+    }
+
+    async fn find_orphan_labels(&self) -> Result<OrphanLabels, TodoistAPIError> {
+        self.record_call("find_orphan_labels", vec![]);
+        self.next_response("find_orphan_labels")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internal::request::APIParametersError;
+
+    #[tokio::test]
+    async fn returns_scripted_responses_in_order() {
+        let api = FakeTodoistApi::new();
+        api.push_response("close_task", Ok(())).push_response::<()>(
+            "close_task",
+            Err(APIParametersError {
+                message: "scripted".into(),
+            }
+            .into()),
+        );
+        assert!(api.close_task("1".into()).await.is_ok());
+        assert!(api.close_task("2".into()).await.is_err());
+        assert_eq!(
+            api.get_calls_to("close_task"),
+            vec![
+                RecordedCall {
+                    operation: "close_task",
+                    arguments: vec![r#""1""#.into()],
+                },
+                RecordedCall {
+                    operation: "close_task",
+                    arguments: vec![r#""2""#.into()],
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn streams_scripted_items() {
+        let api = FakeTodoistApi::new();
+        let project_id = TaskOrProjectID::Project("2203306141".into());
+        api.push_response("stream_all_comments", Ok(Vec::<Comment>::new()))
+            .push_response::<Vec<Comment>>(
+                "stream_all_comments",
+                Err(APIParametersError {
+                    message: "scripted".into(),
+                }
+                .into()),
+            );
+        assert_eq!(api.stream_all_comments(&project_id).count().await, 0);
+        let results: Vec<_> = api.stream_all_comments(&project_id).collect().await;
+        assert!(matches!(results[..], [Err(_)]));
+        assert_eq!(api.get_calls_to("stream_all_comments").len(), 2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "No response was scripted for 'get_all_projects'")]
+    async fn panics_without_scripted_response() {
+        let _ = FakeTodoistApi::new().get_all_projects().await;
+    }
+}
@@ -0,0 +1,137 @@
+//! Translation of API v2 IDs into API v1 IDs (<https://developer.todoist.com/api/v1/#tag/Ids>)
+//!
+//! Resources have different IDs in API v2 (legacy numeric IDs) and API v1.
+//! Functions that always use API v1 (such as the Sync API) translate the IDs that they receive
+//! when the config uses [ApiVersion::V2].
+
+use serde::Deserialize;
+
+use crate::internal::request::paths::create_path;
+use crate::internal::request::v1::ApiModel;
+use crate::internal::request::{paths, send_todoist_versioned_get_request, TodoistAPIError};
+use crate::todoist_config::{ApiVersion, TodoistConfig};
+
+/// The kind of resource that an ID belongs to
+#[derive(Debug, Clone, Copy)]
+pub enum IdKind {
+    Task,
+    Project,
+    Section,
+}
+
+impl IdKind {
+    fn get_path(&self) -> &'static str {
+        match self {
+            IdKind::Task => paths::TASKS,
+            IdKind::Project => paths::PROJECTS,
+            IdKind::Section => paths::SECTIONS,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct IdMapping {
+    old_id: Option<String>,
+    new_id: Option<String>,
+}
+
+impl ApiModel for IdMapping {
+    type V1 = IdMapping;
+
+    fn from_v1(value: Self::V1) -> Self {
+        value
+    }
+}
+
+/// Get the API v1 ID of a resource, from its ID in the [ApiVersion] of the config
+///
+/// IDs without a mapping (ex: IDs that are already API v1 IDs) are returned unchanged.
+pub async fn get_v1_id(
+    config: &TodoistConfig,
+    kind: IdKind,
+    id: String,
+) -> Result<String, TodoistAPIError> {
+    if config.get_api_version() == ApiVersion::V1 {
+        return Ok(id);
+    }
+    let mappings: Vec<IdMapping> = send_todoist_versioned_get_request(
+        config,
+        ApiVersion::V1,
+        create_path(&[paths::ID_MAPPINGS, kind.get_path(), &id]),
+    )
+    .await?;
+    Ok(mappings
+        .into_iter()
+        .find(|mapping| mapping.old_id.as_ref() == Some(&id))
+        .and_then(|mapping| mapping.new_id)
+        .unwrap_or(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    struct IdMappingsEndpoint;
+
+    #[async_trait]
+    impl Middleware for IdMappingsEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let body = match request.url.as_str() {
+                "https://api.todoist.com/api/v1/id_mappings/tasks/2995104339" => {
+                    r#"[{"old_id": "2995104339", "new_id": "6X7rM8997g3RQmvh"}]"#
+                }
+                "https://api.todoist.com/api/v1/id_mappings/tasks/6X7rfFVPjhvv84XG" => "[]",
+                "https://api.todoist.com/api/v1/id_mappings/projects/2203306141" => {
+                    r#"[{"old_id": "2203306141", "new_id": "6Jf8VQXxpwv56VQ7"}]"#
+                }
+                url => panic!("unexpected URL {}", url),
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.as_bytes().to_vec(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn v2_ids_are_translated() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(IdMappingsEndpoint);
+        assert_eq!(
+            get_v1_id(&config, IdKind::Task, "2995104339".into())
+                .await
+                .unwrap(),
+            "6X7rM8997g3RQmvh"
+        );
+        assert_eq!(
+            get_v1_id(&config, IdKind::Task, "6X7rfFVPjhvv84XG".into())
+                .await
+                .unwrap(),
+            "6X7rfFVPjhvv84XG"
+        );
+        assert_eq!(
+            get_v1_id(&config, IdKind::Project, "2203306141".into())
+                .await
+                .unwrap(),
+            "6Jf8VQXxpwv56VQ7"
+        );
+        // API v1 IDs are never sent to the ID mappings endpoint
+        let config = config.with_api_version(ApiVersion::V1);
+        assert_eq!(
+            get_v1_id(&config, IdKind::Task, "6X7rM8997g3RQmvh".into())
+                .await
+                .unwrap(),
+            "6X7rM8997g3RQmvh"
+        );
+    }
+}
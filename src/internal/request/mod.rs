@@ -1,87 +1,372 @@
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::num::NonZeroU16;
+use std::time::Duration;
 
-use reqwest::Method;
+use bytes::Bytes;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{Method, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use uuid::Uuid;
 
-use crate::todoist_config::TodoistConfig;
+use crate::internal::request::multipart::MultipartForm;
+use crate::internal::request::v1::ApiModel;
+use crate::middleware::{TodoistRequest, TodoistResponse};
+use crate::todoist_config::{ApiVersion, TodoistConfig};
 
+pub mod id_mappings;
 pub mod models;
+pub mod multipart;
+pub mod pagination;
 pub mod paths;
+pub mod sync;
+pub mod v1;
 
-const TODOIST_API_URL: &str = "https://api.todoist.com/rest/v2";
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
 
-pub async fn send_todoist_get_request<T: DeserializeOwned>(
+pub async fn send_todoist_get_request<T: ApiModel>(
     config: &TodoistConfig,
     path: String,
 ) -> Result<T, TodoistAPIError> {
-    send_todoist_request::<(), T>(config, path, None, RequestMethod::Get, false)
-        .await
-        .map(|res| res.unwrap())
+    send_todoist_versioned_get_request(config, config.api_version, path).await
+}
+
+/// Sends a GET request to the given [ApiVersion], regardless of the [ApiVersion] of the config
+pub async fn send_todoist_versioned_get_request<T: ApiModel>(
+    config: &TodoistConfig,
+    api_version: ApiVersion,
+    path: String,
+) -> Result<T, TodoistAPIError> {
+    send_todoist_request::<(), T>(config, api_version, path, None, RequestMethod::Get, false).await
 }
 
-pub async fn send_todoist_post_request<Req: Serialize + ?Sized, Res: DeserializeOwned>(
+pub async fn send_todoist_post_request<Req: Serialize + ?Sized, Res: ApiModel>(
     config: &TodoistConfig,
     path: String,
     data: Option<&Req>,
     include_request_id: bool,
 ) -> Result<Res, TodoistAPIError> {
-    send_todoist_request::<Req, Res>(config, path, data, RequestMethod::Post, include_request_id)
+    send_todoist_versioned_post_request(config, config.api_version, path, data, include_request_id)
         .await
-        .map(|res| res.unwrap())
+}
+
+/// Sends a POST request to the given [ApiVersion], regardless of the [ApiVersion] of the config
+pub async fn send_todoist_versioned_post_request<Req: Serialize + ?Sized, Res: ApiModel>(
+    config: &TodoistConfig,
+    api_version: ApiVersion,
+    path: String,
+    data: Option<&Req>,
+    include_request_id: bool,
+) -> Result<Res, TodoistAPIError> {
+    send_todoist_request::<Req, Res>(
+        config,
+        api_version,
+        path,
+        data,
+        RequestMethod::Post,
+        include_request_id,
+    )
+    .await
 }
 
 pub async fn send_todoist_delete_request(
     config: &TodoistConfig,
     path: String,
 ) -> Result<(), TodoistAPIError> {
-    send_todoist_request::<(), ()>(config, path, None, RequestMethod::Delete, false)
-        .await
-        .map(|_| ())
+    send_todoist_request::<(), ()>(
+        config,
+        config.api_version,
+        path,
+        None,
+        RequestMethod::Delete,
+        false,
+    )
+    .await
 }
 
-async fn send_todoist_request<Req: Serialize + ?Sized, Res: DeserializeOwned>(
+async fn send_todoist_request<Req: Serialize + ?Sized, Res: ApiModel>(
     config: &TodoistConfig,
+    api_version: ApiVersion,
     path: String,
     data: Option<&Req>,
     method: RequestMethod,
     include_request_id: bool,
-) -> Result<Option<Res>, TodoistAPIError> {
+) -> Result<Res, TodoistAPIError> {
     if !path.starts_with('/') {
         panic!("Path must start with a '/'! Instead was '{}'", path);
     }
-    let client = &config.client;
-    let mut builder = client.request(method.into(), format!("{}{}", TODOIST_API_URL, &path));
-    if let Some(data) = data {
-        builder = builder.json(data);
+    let body = data.map(RequestBody::json).transpose()?;
+    let response =
+        execute_request(config, api_version, &path, body, method, include_request_id).await?;
+    // Responses without content (status code 204) are decoded from `null`, which works for `()`
+    let response = if response.is_empty() {
+        b"null"
+    } else {
+        &response[..]
+    };
+    Ok(match api_version {
+        ApiVersion::V2 => serde_json::from_slice(response)?,
+        ApiVersion::V1 => Res::from_v1(serde_json::from_slice(response)?),
+    })
+}
+
+/// Sends a request to the Sync endpoint of API v1, regardless of the [ApiVersion] of the config
+pub async fn send_todoist_sync_request<Req: Serialize, Res: DeserializeOwned>(
+    config: &TodoistConfig,
+    data: &Req,
+) -> Result<Res, TodoistAPIError> {
+    let response = execute_request(
+        config,
+        ApiVersion::V1,
+        paths::SYNC,
+        Some(RequestBody::json(data)?),
+        RequestMethod::Post,
+        true,
+    )
+    .await?;
+    Ok(serde_json::from_slice(&response)?)
+}
+
+/// Sends a `multipart/form-data` request to API v1, regardless of the [ApiVersion] of the config
+pub async fn send_todoist_multipart_request<Res: DeserializeOwned>(
+    config: &TodoistConfig,
+    path: &str,
+    form: MultipartForm,
+) -> Result<Res, TodoistAPIError> {
+    let body = RequestBody {
+        content_type: HeaderValue::from_str(&form.get_content_type()).unwrap(),
+        content: form.finish().into(),
+    };
+    let response = execute_request(
+        config,
+        ApiVersion::V1,
+        path,
+        Some(body),
+        RequestMethod::Post,
+        true,
+    )
+    .await?;
+    Ok(serde_json::from_slice(&response)?)
+}
+
+/// The body of a request and its `Content-Type`
+struct RequestBody {
+    content: Bytes,
+    content_type: HeaderValue,
+}
+
+impl RequestBody {
+    fn json<T: Serialize + ?Sized>(data: &T) -> Result<RequestBody, serde_json::Error> {
+        Ok(RequestBody {
+            content: serde_json::to_vec(data)?.into(),
+            content_type: HeaderValue::from_static("application/json"),
+        })
+    }
+}
+
+/// Sends a request through the [middleware](crate::middleware) of the config,
+/// returning the response body
+///
+/// Every request (including Sync and `multipart/form-data` requests) is sent through this function,
+/// so that all of them are recorded in a `todoist_request` span.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "todoist_request",
+        skip_all,
+        fields(
+            method = %Method::from(method),
+            path = %paths::get_path_template(path),
+            status = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            retries = tracing::field::Empty,
+            request_id = tracing::field::Empty,
+        )
+    )
+)]
+async fn execute_request(
+    config: &TodoistConfig,
+    api_version: ApiVersion,
+    path: &str,
+    body: Option<RequestBody>,
+    method: RequestMethod,
+    include_request_id: bool,
+) -> Result<Vec<u8>, TodoistAPIError> {
+    #[cfg(feature = "tracing")]
+    let start = std::time::Instant::now();
+    let mut request = TodoistRequest {
+        method: method.into(),
+        url: format!("{}{}", api_version.get_base_url(), path),
+        headers: HeaderMap::new(),
+        body: None,
+    };
+    if let Some(body) = body {
+        request.headers.insert(CONTENT_TYPE, body.content_type);
+        request.body = Some(body.content);
     }
     if include_request_id {
-        builder = builder.header("X-Request-Id", Uuid::new_v4().as_simple().to_string());
+        request.headers.insert(
+            REQUEST_ID_HEADER,
+            HeaderValue::from_str(&Uuid::new_v4().as_simple().to_string()).unwrap(),
+        );
+    }
+    // The latency is recorded even if the request fails
+    let result = async {
+        let mut called_middleware = 0;
+        let mut short_circuit_response = None;
+        for middleware in &config.middleware {
+            called_middleware += 1;
+            if let Some(response) = middleware.before_request(&mut request).await? {
+                short_circuit_response = Some(response);
+                break;
+            }
+        }
+        #[cfg(feature = "tracing")]
+        if let Some(request_id) = request.headers.get(REQUEST_ID_HEADER) {
+            tracing::Span::current().record("request_id", request_id.to_str().unwrap_or_default());
+        }
+        let mut response = match short_circuit_response {
+            Some(response) => response,
+            None => send_request_with_policies(config, &request).await?,
+        };
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", response.status.as_u16());
+        for middleware in config.middleware[..called_middleware].iter().rev() {
+            middleware.after_response(&request, &mut response).await?;
+        }
+        check_status(response.status)?;
+        Ok(response.body)
+    }
+    .await;
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("latency_ms", start.elapsed().as_millis() as u64);
+    result
+}
+
+/// Sends a request through the [retry policy](crate::middleware::RetryPolicy),
+/// [rate limiter](crate::middleware::RateLimiter) and [response cache](crate::middleware::ResponseCache)
+/// of the config
+async fn send_request_with_policies(
+    config: &TodoistConfig,
+    request: &TodoistRequest,
+) -> Result<TodoistResponse, TodoistAPIError> {
+    let is_get_request = request.method == Method::GET;
+    let cache_key = config.get_cache_key(&request.url);
+    if let (true, Some(cache)) = (is_get_request, &config.response_cache) {
+        if let Some(cached) = cache.get(&cache_key) {
+            return Ok(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: cached,
+            });
+        }
+    }
+    // Without a request ID, Todoist cannot tell that a retried request is a duplicate
+    let is_idempotent =
+        request.method != Method::POST || request.headers.contains_key(REQUEST_ID_HEADER);
+    let mut retries = 0;
+    let mut refreshed_token = false;
+    let response = loop {
+        if let Some(rate_limiter) = &config.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+        let result = send_request(config, request).await;
+        if let Ok(response) = &result {
+            if response.status == StatusCode::UNAUTHORIZED
+                && !refreshed_token
+                && config.refresh_token().await
+            {
+                refreshed_token = true;
+                continue;
+            }
+        }
+        let Some(retry_policy) = &config.retry_policy else {
+            break result?;
+        };
+        let should_retry = match &result {
+            Ok(response) => {
+                response.status == StatusCode::TOO_MANY_REQUESTS
+                    || (response.status.is_server_error() && is_idempotent)
+            }
+            Err(_) => is_idempotent,
+        };
+        if !should_retry || retries >= retry_policy.max_retries {
+            break result?;
+        }
+        retries += 1;
+        let retry_after = result.ok().and_then(|response| get_retry_after(&response));
+        tokio::time::sleep(retry_policy.get_backoff(retries, retry_after)).await;
+    };
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("retries", retries);
+    if let (true, Some(cache)) = (response.status.is_success(), &config.response_cache) {
+        if is_get_request {
+            cache.insert(cache_key, response.body.clone());
+        } else {
+            cache.clear();
+        }
+    }
+    Ok(response)
+}
+
+async fn send_request(
+    config: &TodoistConfig,
+    request: &TodoistRequest,
+) -> Result<TodoistResponse, TodoistAPIError> {
+    let authorization = config
+        .get_authorization_header()
+        .map_err(|e| APIParametersError {
+            message: e.to_string(),
+        })?;
+    let mut builder = config
+        .client
+        .request(request.method.clone(), &request.url)
+        .headers(request.headers.clone())
+        .header(AUTHORIZATION, authorization);
+    if let Some(body) = &request.body {
+        builder = builder.body(body.clone());
     }
     let response = builder.send().await?;
-    if response.status().is_client_error() {
+    Ok(TodoistResponse {
+        status: response.status(),
+        headers: response.headers().clone(),
+        body: response.bytes().await?.to_vec(),
+    })
+}
+
+fn get_retry_after(response: &TodoistResponse) -> Option<Duration> {
+    response
+        .headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+pub fn check_response_status(response: &Response) -> Result<(), TodoistAPIError> {
+    check_status(response.status())
+}
+
+fn check_status(status: StatusCode) -> Result<(), TodoistAPIError> {
+    if status.is_client_error() {
         return Err(InvalidRequestError {
-            status_code: NonZeroU16::new(response.status().as_u16()).unwrap(),
+            status_code: NonZeroU16::new(status.as_u16()).unwrap(),
         }
         .into());
     }
-    if response.status().is_server_error() {
+    if status.is_server_error() {
         return Err(ServerError {
-            status_code: NonZeroU16::new(response.status().as_u16()).unwrap(),
+            status_code: NonZeroU16::new(status.as_u16()).unwrap(),
         }
         .into());
     }
-    if response.status() == 204 {
-        return Ok(None);
-    }
-    let result: Res = response.json().await?;
-    Ok(Some(result))
+    Ok(())
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 enum RequestMethod {
     Get,
     Post,
@@ -114,11 +399,17 @@ pub enum TodoistAPIError {
     APIParametersError(APIParametersError),
     /// If there was an error while parsing a JSON response
     ResponseJSONParseError(serde_json::Error),
+    /// If a command sent to the Sync API failed
+    SyncCommandError(SyncCommandError),
+    /// If there was an error while reading or writing a file
+    IOError(std::io::Error),
+    /// If a downloaded file does not have the expected checksum
+    ChecksumMismatch(ChecksumMismatchError),
 }
 
 #[derive(Debug, Clone)]
 pub struct InvalidRequestError {
-    status_code: NonZeroU16,
+    pub(crate) status_code: NonZeroU16,
 }
 
 impl Display for InvalidRequestError {
@@ -155,6 +446,60 @@ impl Display for APIParametersError {
 
 impl Error for APIParametersError {}
 
+#[derive(Debug, Clone)]
+pub struct SyncCommandError {
+    pub(crate) error_code: i64,
+    pub(crate) message: String,
+}
+
+impl Display for SyncCommandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Sync command error - {} (error code: {})",
+            self.message, self.error_code
+        )
+    }
+}
+
+impl Error for SyncCommandError {}
+
+#[derive(Debug, Clone)]
+pub struct ChecksumMismatchError {
+    pub(crate) file_name: String,
+    pub(crate) expected: String,
+    pub(crate) actual: String,
+}
+
+impl ChecksumMismatchError {
+    /// The name of the downloaded file
+    pub fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    /// The checksum that the file was expected to have
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// The checksum of the downloaded file
+    pub fn actual(&self) -> &str {
+        &self.actual
+    }
+}
+
+impl Display for ChecksumMismatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Checksum mismatch - The checksum of {} is {}, expected {}",
+            self.file_name, self.actual, self.expected
+        )
+    }
+}
+
+impl Error for ChecksumMismatchError {}
+
 impl Error for TodoistAPIError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -163,6 +508,9 @@ impl Error for TodoistAPIError {
             TodoistAPIError::RequestSendError(ref e) => Some(e),
             TodoistAPIError::ResponseJSONParseError(ref e) => Some(e),
             TodoistAPIError::APIParametersError(ref e) => Some(e),
+            TodoistAPIError::SyncCommandError(ref e) => Some(e),
+            TodoistAPIError::IOError(ref e) => Some(e),
+            TodoistAPIError::ChecksumMismatch(ref e) => Some(e),
         }
     }
 }
@@ -202,3 +550,21 @@ impl From<APIParametersError> for TodoistAPIError {
         TodoistAPIError::APIParametersError(value)
     }
 }
+
+impl From<SyncCommandError> for TodoistAPIError {
+    fn from(value: SyncCommandError) -> Self {
+        TodoistAPIError::SyncCommandError(value)
+    }
+}
+
+impl From<std::io::Error> for TodoistAPIError {
+    fn from(value: std::io::Error) -> Self {
+        TodoistAPIError::IOError(value)
+    }
+}
+
+impl From<ChecksumMismatchError> for TodoistAPIError {
+    fn from(value: ChecksumMismatchError) -> Self {
+        TodoistAPIError::ChecksumMismatch(value)
+    }
+}
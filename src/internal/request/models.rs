@@ -1,8 +1,12 @@
+use std::collections::BTreeMap;
+
 use serde::Serialize;
 
+use crate::model::collaborator::CollaboratorRole;
 use crate::model::color::Color;
 use crate::model::comment::CommentAttachment;
 use crate::model::project::ProjectViewStyle;
+use crate::model::reminder::ReminderType;
 use crate::model::task::TaskDurationUnit;
 
 #[derive(Debug, Clone, Serialize)]
@@ -14,6 +18,8 @@ pub struct CreateNewCommentArgs {
     pub content: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment: Option<CommentAttachment>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uids_to_notify: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -92,6 +98,49 @@ pub struct UpdateSectionArgs {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionIdArgs {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MoveSectionArgs {
+    pub id: String,
+    pub project_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReorderTasksArgs {
+    pub items: Vec<ChildOrderArgs>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReorderProjectsArgs {
+    pub projects: Vec<ChildOrderArgs>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChildOrderArgs {
+    pub id: String,
+    pub child_order: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReorderSectionsArgs {
+    pub sections: Vec<SectionOrderArgs>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SectionOrderArgs {
+    pub id: String,
+    pub section_order: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateLabelOrdersArgs {
+    pub id_order_mapping: BTreeMap<String, u32>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateNewTaskArgs {
     pub content: String,
@@ -125,3 +174,46 @@ pub struct UpdateTaskArgs {
     pub duration: Option<u64>,
     pub duration_unit: Option<TaskDurationUnit>,
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateNewReminderArgs {
+    pub item_id: String,
+    #[serde(flatten)]
+    pub reminder_type: ReminderType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_uid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateReminderArgs {
+    pub id: String,
+    #[serde(flatten)]
+    pub reminder_type: Option<ReminderType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_uid: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteReminderArgs {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareProjectArgs {
+    pub project_id: String,
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<CollaboratorRole>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvitationArgs {
+    pub invitation_id: String,
+    pub invitation_secret: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteCollaboratorArgs {
+    pub project_id: String,
+    pub email: String,
+}
@@ -0,0 +1,123 @@
+//! A minimal `multipart/form-data` encoder (<https://www.rfc-editor.org/rfc/rfc7578>)
+//!
+//! The body is built in memory so that it goes through the [middleware](crate::middleware)
+//! like every other request. Files are read directly into the body, so their content is only held once.
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+use uuid::Uuid;
+
+pub struct MultipartForm {
+    boundary: String,
+    content: Vec<u8>,
+}
+
+impl MultipartForm {
+    pub fn new() -> MultipartForm {
+        MultipartForm::with_boundary(format!("todoist-{}", Uuid::new_v4().as_simple()))
+    }
+
+    fn with_boundary(boundary: String) -> MultipartForm {
+        MultipartForm {
+            boundary,
+            content: Vec::new(),
+        }
+    }
+
+    /// Adds a text field
+    pub fn add_text(&mut self, name: &str, value: &str) {
+        self.add_part(
+            format!("form-data; name=\"{}\"", escape(name)),
+            None,
+            value.as_bytes(),
+        );
+    }
+
+    /// Adds a file field whose content is read until the end of `reader`, returning the size of the file
+    pub async fn add_file(
+        &mut self,
+        name: &str,
+        file_name: &str,
+        mime_type: &str,
+        mut reader: impl AsyncRead + Unpin,
+    ) -> std::io::Result<u64> {
+        self.add_part_headers(
+            format!(
+                "form-data; name=\"{}\"; filename=\"{}\"",
+                escape(name),
+                escape(file_name)
+            ),
+            Some(mime_type),
+        );
+        let size = reader.read_to_end(&mut self.content).await?;
+        self.content.extend_from_slice(b"\r\n");
+        Ok(size as u64)
+    }
+
+    /// The value of the `Content-Type` header of the request
+    pub fn get_content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Returns the body of the request
+    pub fn finish(mut self) -> Vec<u8> {
+        self.content
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.content
+    }
+
+    fn add_part(&mut self, disposition: String, content_type: Option<&str>, content: &[u8]) {
+        self.add_part_headers(disposition, content_type);
+        self.content.extend_from_slice(content);
+        self.content.extend_from_slice(b"\r\n");
+    }
+
+    fn add_part_headers(&mut self, disposition: String, content_type: Option<&str>) {
+        let mut headers = format!(
+            "--{}\r\nContent-Disposition: {}\r\n",
+            self.boundary, disposition
+        );
+        if let Some(content_type) = content_type {
+            headers.push_str(&format!("Content-Type: {}\r\n", content_type));
+        }
+        headers.push_str("\r\n");
+        self.content.extend_from_slice(headers.as_bytes());
+    }
+}
+
+/// Percent-encodes the characters that would end a quoted header parameter
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn encodes_parts() {
+        let mut form = MultipartForm::with_boundary("abc".into());
+        form.add_text("project_id", "2203306141");
+        let size = form
+            .add_file("file", "my \"notes\".txt", "text/plain", &b"Buy Milk"[..])
+            .await
+            .unwrap();
+        assert_eq!(size, 8);
+        assert_eq!(form.get_content_type(), "multipart/form-data; boundary=abc");
+        assert_eq!(
+            String::from_utf8(form.finish()).unwrap(),
+            "--abc\r\n\
+             Content-Disposition: form-data; name=\"project_id\"\r\n\
+             \r\n\
+             2203306141\r\n\
+             --abc\r\n\
+             Content-Disposition: form-data; name=\"file\"; filename=\"my %22notes%22.txt\"\r\n\
+             Content-Type: text/plain\r\n\
+             \r\n\
+             Buy Milk\r\n\
+             --abc--\r\n"
+        );
+    }
+}
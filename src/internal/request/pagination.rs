@@ -0,0 +1,186 @@
+use futures_util::stream::{self, Stream, TryStreamExt};
+use reqwest::Url;
+use serde::{Deserialize, Deserializer};
+
+use crate::internal::request::v1::ApiModel;
+use crate::internal::request::{paths, send_todoist_versioned_get_request, TodoistAPIError};
+use crate::todoist_config::{ApiVersion, TodoistConfig};
+
+/// A page of results returned by a listing endpoint
+///
+/// Endpoints without pagination (every API v2 endpoint) return a plain list, which is read as a single page.
+/// Pages are always decoded in full, so streams only avoid holding more than one page at a time.
+pub struct Page<T> {
+    pub results: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PageResponse<T> {
+    List(Vec<T>),
+    Paginated {
+        results: Vec<T>,
+        next_cursor: Option<String>,
+    },
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Page<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match PageResponse::deserialize(deserializer)? {
+            PageResponse::List(results) => Page {
+                results,
+                next_cursor: None,
+            },
+            PageResponse::Paginated {
+                results,
+                next_cursor,
+            } => Page {
+                results,
+                next_cursor,
+            },
+        })
+    }
+}
+
+/// Requests the pages of `path` from the given [ApiVersion] one after the other,
+/// following the `next_cursor` of each page
+pub fn stream_versioned_pages<'a, T: ApiModel + 'a>(
+    config: &'a TodoistConfig,
+    api_version: ApiVersion,
+    path: String,
+) -> impl Stream<Item = Result<Page<T>, TodoistAPIError>> + 'a {
+    // `None` once the last page was requested, otherwise the cursor of the next page
+    stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+        let path = path.clone();
+        async move {
+            let Some(cursor) = cursor else {
+                return Ok(None);
+            };
+            let path = add_cursor(path, cursor.as_deref());
+            let page: Page<T> =
+                send_todoist_versioned_get_request(config, api_version, path).await?;
+            let next_cursor = page.next_cursor.clone().map(Some);
+            Ok(Some((page, next_cursor)))
+        }
+    })
+}
+
+/// Requests the pages of `path` as they are consumed, yielding their results one by one
+pub fn stream_results<'a, T: ApiModel + 'a>(
+    config: &'a TodoistConfig,
+    path: String,
+) -> impl Stream<Item = Result<T, TodoistAPIError>> + 'a {
+    stream_versioned_results(config, config.api_version, path)
+}
+
+/// [stream_results] for the given [ApiVersion], regardless of the [ApiVersion] of the config
+pub fn stream_versioned_results<'a, T: ApiModel + 'a>(
+    config: &'a TodoistConfig,
+    api_version: ApiVersion,
+    path: String,
+) -> impl Stream<Item = Result<T, TodoistAPIError>> + 'a {
+    stream_versioned_pages(config, api_version, path)
+        .map_ok(|page: Page<T>| stream::iter(page.results.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// Requests every page of `path`, collecting all of their results
+pub async fn get_all_results<T: ApiModel>(
+    config: &TodoistConfig,
+    path: String,
+) -> Result<Vec<T>, TodoistAPIError> {
+    stream_results(config, path).try_collect().await
+}
+
+/// Adds the percent-encoded `cursor` to the parameters of `path`
+fn add_cursor(path: String, cursor: Option<&str>) -> String {
+    let Some(cursor) = cursor else {
+        return path;
+    };
+    // Only the path and query of the URL are used, so any base URL works
+    let mut url = Url::parse(&format!("{}{}", ApiVersion::V1.get_base_url(), path)).unwrap();
+    url.query_pairs_mut()
+        .append_pair(paths::PARAM_CURSOR.trim_end_matches('='), cursor);
+    format!(
+        "{}?{}",
+        path.split('?').next().unwrap_or_default(),
+        url.query().unwrap_or_default()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use futures_util::StreamExt;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    struct Pages;
+
+    #[async_trait]
+    impl Middleware for Pages {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let body = match request.url.split_once('?').map(|(_, query)| query) {
+                Some("label=Food") => r#"{"results": ["1", "2"], "next_cursor": "a"}"#,
+                Some("label=Food&cursor=a") => r#"{"results": [], "next_cursor": "b"}"#,
+                Some("label=Food&cursor=b") => r#"{"results": ["3"], "next_cursor": null}"#,
+                None => r#"["4", "5"]"#,
+                query => panic!("unexpected query {:?}", query),
+            };
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.as_bytes().to_vec(),
+            }))
+        }
+    }
+
+    fn create_config() -> TodoistConfig {
+        TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(Pages)
+    }
+
+    #[tokio::test]
+    async fn follows_cursors() {
+        let config = create_config();
+        let results = stream_results::<String>(&config, "/tasks?label=Food".into())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            results.into_iter().map(Result::unwrap).collect::<Vec<_>>(),
+            ["1", "2", "3"]
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_are_a_single_page() {
+        let config = create_config();
+        let pages = stream_versioned_pages::<String>(&config, ApiVersion::V2, "/tasks".into())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].as_ref().unwrap().results, ["4", "5"]);
+    }
+
+    #[test]
+    fn cursor_parameter() {
+        assert_eq!(add_cursor("/tasks".into(), None), "/tasks");
+        assert_eq!(add_cursor("/tasks".into(), Some("a")), "/tasks?cursor=a");
+        assert_eq!(
+            add_cursor("/tasks?label=Food".into(), Some("a")),
+            "/tasks?label=Food&cursor=a"
+        );
+        assert_eq!(
+            add_cursor("/tasks".into(), Some("a+b/c=&d")),
+            "/tasks?cursor=a%2Bb%2Fc%3D%26d"
+        );
+    }
+}
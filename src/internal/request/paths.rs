@@ -1,26 +1,82 @@
 // Additional paths
+pub const ARCHIVE: &str = "/archive";
+pub const ARCHIVED: &str = "/archived";
 pub const CLOSE: &str = "/close";
 pub const COLLABORATORS: &str = "/collaborators";
+pub const FILTER: &str = "/filter";
 pub const REOPEN: &str = "/reopen";
+pub const UNARCHIVE: &str = "/unarchive";
 
 // Base paths
+pub const ACCESS_TOKENS: &str = "/access_tokens";
 pub const COMMENTS: &str = "/comments";
+pub const ID_MAPPINGS: &str = "/id_mappings";
 pub const LABELS: &str = "/labels";
 pub const LABELS_SHARED: &str = "/labels/shared";
 pub const LABELS_SHARED_RENAME: &str = "/labels/shared/rename";
 pub const LABELS_SHARED_REMOVE: &str = "/labels/shared/remove";
 pub const PROJECTS: &str = "/projects";
 pub const SECTIONS: &str = "/sections";
+pub const SYNC: &str = "/sync";
 pub const TASKS: &str = "/tasks";
+pub const UPLOADS: &str = "/uploads";
 
 // Parameters
+pub const PARAM_CURSOR: &str = "cursor=";
 pub const PARAM_FILTER: &str = "filter=";
 pub const PARAM_IDS: &str = "ids=";
 pub const PARAM_LABEL: &str = "label=";
 pub const PARAM_LANG: &str = "lang=";
 pub const PARAM_PROJECT_ID: &str = "project_id=";
+pub const PARAM_QUERY: &str = "query=";
 pub const PARAM_SECTION_ID: &str = "section_id=";
 
+/// Every path segment that is not an ID
+///
+/// Every path above must be listed here, otherwise its segments are recorded as IDs.
+#[cfg(feature = "tracing")]
+const STATIC_PATHS: &[&str] = &[
+    ARCHIVE,
+    ARCHIVED,
+    CLOSE,
+    COLLABORATORS,
+    FILTER,
+    REOPEN,
+    UNARCHIVE,
+    ACCESS_TOKENS,
+    COMMENTS,
+    ID_MAPPINGS,
+    LABELS,
+    LABELS_SHARED,
+    LABELS_SHARED_RENAME,
+    LABELS_SHARED_REMOVE,
+    PROJECTS,
+    SECTIONS,
+    SYNC,
+    TASKS,
+    UPLOADS,
+];
+
+/// Get the path without its parameters and with every ID replaced by `{id}`
+/// (ex: `/tasks/{id}/close`), so that it can be recorded without identifying data
+#[cfg(feature = "tracing")]
+pub fn get_path_template(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    path.split('/')
+        .skip(1)
+        .map(|segment| {
+            let is_static = STATIC_PATHS
+                .iter()
+                .any(|static_path| static_path.split('/').any(|part| part == segment));
+            if is_static {
+                format!("/{}", segment)
+            } else {
+                "/{id}".to_string()
+            }
+        })
+        .collect()
+}
+
 pub fn create_path(paths: &[impl ToString]) -> String {
     let mut final_path = String::new();
     let mut last_was_param = false;
@@ -99,6 +155,29 @@ mod tests {
         )
     }
 
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn path_templates() {
+        assert_eq!(get_path_template("/projects"), "/projects");
+        assert_eq!(
+            get_path_template("/tasks/2995104339/close"),
+            "/tasks/{id}/close"
+        );
+        assert_eq!(
+            get_path_template("/tasks?project_id=2203306141&label=Food"),
+            "/tasks"
+        );
+        assert_eq!(
+            get_path_template("/labels/shared/rename"),
+            "/labels/shared/rename"
+        );
+        assert_eq!(get_path_template("/comments/Shopping"), "/comments/{id}");
+        // Workspaces, reminders and uploads are requested through these API v1 paths
+        for path in [SYNC, UPLOADS, LABELS, PROJECTS] {
+            assert_eq!(get_path_template(path), path);
+        }
+    }
+
     #[test]
     #[should_panic(expected = "Cannot have a path with a slash after a parameter")]
     fn invalid_path_with_slash_after_param_key() {
@@ -0,0 +1,238 @@
+//! Commands and resources of the Todoist Sync API (<https://developer.todoist.com/api/v1/#tag/Sync>)
+//!
+//! Some operations (such as reminders) are only available through the Sync API,
+//! which is always part of API v1.
+
+use std::collections::HashMap;
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::internal::request::{send_todoist_sync_request, SyncCommandError, TodoistAPIError};
+use crate::todoist_config::TodoistConfig;
+
+/// A command that changes a resource
+#[derive(Serialize, Debug)]
+pub struct SyncCommand<A: Serialize> {
+    #[serde(rename = "type")]
+    pub command_type: &'static str,
+    /// A unique ID, which lets Todoist ignore commands that were already applied
+    pub uuid: String,
+    /// A temporary ID for created resources, mapped to the real ID in the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temp_id: Option<String>,
+    pub args: A,
+}
+
+impl<A: Serialize> SyncCommand<A> {
+    pub fn new(command_type: &'static str, args: A) -> SyncCommand<A> {
+        SyncCommand {
+            command_type,
+            uuid: create_id(),
+            temp_id: None,
+            args,
+        }
+    }
+
+    /// Creates a command that creates a resource, with a random temporary ID
+    pub fn new_with_temp_id(command_type: &'static str, args: A) -> SyncCommand<A> {
+        SyncCommand {
+            temp_id: Some(create_id()),
+            ..SyncCommand::new(command_type, args)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SyncCommandsArgs<'a, A: Serialize> {
+    commands: &'a [SyncCommand<A>],
+    // Only set to read resources after the commands were applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sync_token: Option<&'static str>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    resource_types: &'a [&'a str],
+}
+
+#[derive(Deserialize)]
+struct SyncCommandsResponse<Res> {
+    // `"ok"`, an error, or the status of each ID for commands that change multiple resources
+    sync_status: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    temp_id_mapping: HashMap<String, String>,
+    #[serde(flatten)]
+    resources: Res,
+}
+
+#[derive(Deserialize)]
+struct SyncStatusError {
+    error_code: i64,
+    error: String,
+}
+
+#[derive(Serialize)]
+struct ReadResourcesArgs<'a> {
+    sync_token: &'static str,
+    resource_types: &'a [&'a str],
+}
+
+/// Sends `commands` in a single request, returning the mapping of temporary IDs to real IDs
+///
+/// Fails with the error of the first command that failed (the other commands may still be applied).
+pub async fn send_sync_commands<A: Serialize>(
+    config: &TodoistConfig,
+    commands: &[SyncCommand<A>],
+) -> Result<HashMap<String, String>, TodoistAPIError> {
+    if commands.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let response: SyncCommandsResponse<IgnoredAny> = send_todoist_sync_request(
+        config,
+        &SyncCommandsArgs {
+            commands,
+            sync_token: None,
+            resource_types: &[],
+        },
+    )
+    .await?;
+    check_sync_status(commands, &response.sync_status)?;
+    Ok(response.temp_id_mapping)
+}
+
+/// Sends `commands` and reads every resource of the given types in the same request,
+/// returning the mapping of temporary IDs to real IDs and the resources after the commands were applied
+///
+/// `Res` should contain a field named after each resource type.
+pub async fn send_sync_commands_and_read_resources<A: Serialize, Res: DeserializeOwned>(
+    config: &TodoistConfig,
+    commands: &[SyncCommand<A>],
+    resource_types: &[&str],
+) -> Result<(HashMap<String, String>, Res), TodoistAPIError> {
+    let response: SyncCommandsResponse<Res> = send_todoist_sync_request(
+        config,
+        &SyncCommandsArgs {
+            commands,
+            sync_token: Some("*"),
+            resource_types,
+        },
+    )
+    .await?;
+    check_sync_status(commands, &response.sync_status)?;
+    Ok((response.temp_id_mapping, response.resources))
+}
+
+/// Fails with the error of the first command in `commands` that failed
+fn check_sync_status<A: Serialize>(
+    commands: &[SyncCommand<A>],
+    sync_status: &HashMap<String, serde_json::Value>,
+) -> Result<(), TodoistAPIError> {
+    for command in commands {
+        let status = sync_status.get(&command.uuid);
+        if let Some(Ok(status)) = status.map(SyncStatusError::deserialize) {
+            return Err(SyncCommandError {
+                error_code: status.error_code,
+                message: format!("{} failed - {}", command.command_type, status.error),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Reads every resource of the given types (ex: `reminders`)
+///
+/// `Res` should contain a field named after each resource type.
+pub async fn read_resources<Res: DeserializeOwned>(
+    config: &TodoistConfig,
+    resource_types: &[&str],
+) -> Result<Res, TodoistAPIError> {
+    send_todoist_sync_request(
+        config,
+        &ReadResourcesArgs {
+            sync_token: "*",
+            resource_types,
+        },
+    )
+    .await
+}
+
+fn create_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+    struct SyncEndpoint;
+
+    #[async_trait]
+    impl Middleware for SyncEndpoint {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            assert_eq!(request.url, "https://api.todoist.com/api/v1/sync");
+            let body: serde_json::Value =
+                serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+            let commands = body["commands"].as_array().unwrap();
+            let mut sync_status = serde_json::Map::new();
+            let mut temp_id_mapping = serde_json::Map::new();
+            for command in commands {
+                let uuid = command["uuid"].as_str().unwrap().to_string();
+                if command["args"]["id"] == "missing" {
+                    sync_status.insert(
+                        uuid,
+                        serde_json::json!({"error_code": 22, "error": "Item not found"}),
+                    );
+                } else {
+                    sync_status.insert(uuid, "ok".into());
+                }
+                if let Some(temp_id) = command["temp_id"].as_str() {
+                    temp_id_mapping.insert(temp_id.into(), "2992679862".into());
+                }
+            }
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: serde_json::to_vec(&serde_json::json!({
+                    "sync_status": sync_status,
+                    "temp_id_mapping": temp_id_mapping,
+                }))
+                .unwrap(),
+            }))
+        }
+    }
+
+    #[derive(Serialize)]
+    struct Args {
+        id: &'static str,
+    }
+
+    #[tokio::test]
+    async fn commands_map_temp_ids_and_fail() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(SyncEndpoint);
+        let command = SyncCommand::new_with_temp_id("item_add", Args { id: "new" });
+        let temp_id = command.temp_id.clone().unwrap();
+        let mapping = send_sync_commands(&config, &[command]).await.unwrap();
+        assert_eq!(mapping[&temp_id], "2992679862");
+
+        let error = send_sync_commands(
+            &config,
+            &[SyncCommand::new("item_close", Args { id: "missing" })],
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Sync command error - item_close failed - Item not found (error code: 22)"
+        );
+    }
+}
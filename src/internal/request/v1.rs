@@ -0,0 +1,395 @@
+//! The representations of the models in the Todoist API v1 (<https://developer.todoist.com/api/v1/>)
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::internal::request::pagination::Page;
+use crate::model::collaborator::Collaborator;
+use crate::model::color::Color;
+use crate::model::comment::{Comment, CommentAttachment};
+use crate::model::label::PersonalLabel;
+use crate::model::project::{Project, ProjectViewStyle};
+use crate::model::section::Section;
+use crate::model::task::{Task, TaskDueDateTime, TaskDuration};
+
+const TASK_URL: &str = "https://app.todoist.com/app/task/";
+const PROJECT_URL: &str = "https://app.todoist.com/app/project/";
+
+/// A response that is represented differently in API v1
+pub trait ApiModel: DeserializeOwned {
+    /// The representation in API v1
+    type V1: DeserializeOwned;
+
+    fn from_v1(value: Self::V1) -> Self;
+}
+
+macro_rules! same_in_v1 {
+    ($($model:ty),*) => {
+        $(
+            impl ApiModel for $model {
+                type V1 = $model;
+
+                fn from_v1(value: Self::V1) -> Self {
+                    value
+                }
+            }
+        )*
+    };
+}
+
+same_in_v1!((), String, Collaborator);
+
+impl<T: ApiModel> ApiModel for Vec<T> {
+    type V1 = Vec<T::V1>;
+
+    fn from_v1(value: Self::V1) -> Self {
+        value.into_iter().map(T::from_v1).collect()
+    }
+}
+
+impl<T: ApiModel> ApiModel for Page<T> {
+    type V1 = Page<T::V1>;
+
+    fn from_v1(value: Self::V1) -> Self {
+        Page {
+            results: Vec::from_v1(value.results),
+            next_cursor: value.next_cursor,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct V1Task {
+    id: String,
+    project_id: String,
+    section_id: Option<String>,
+    content: String,
+    #[serde(default)]
+    description: String,
+    checked: bool,
+    #[serde(default)]
+    labels: Vec<String>,
+    parent_id: Option<String>,
+    child_order: u32,
+    priority: u8,
+    due: Option<TaskDueDateTime>,
+    #[serde(default)]
+    note_count: u32,
+    added_at: Option<String>,
+    added_by_uid: Option<String>,
+    responsible_uid: Option<String>,
+    assigned_by_uid: Option<String>,
+    duration: Option<TaskDuration>,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ApiModel for Task {
+    type V1 = V1Task;
+
+    fn from_v1(value: V1Task) -> Task {
+        Task {
+            url: format!("{}{}", TASK_URL, value.id),
+            id: value.id,
+            project_id: value.project_id,
+            section_id: value.section_id,
+            content: value.content,
+            description: value.description,
+            is_completed: value.checked,
+            labels: value.labels,
+            parent_id: value.parent_id,
+            order: value.child_order,
+            priority: value.priority,
+            due: value.due,
+            comment_count: value.note_count,
+            created_at: value.added_at,
+            creator_id: value.added_by_uid,
+            assignee_id: value.responsible_uid,
+            assigner_id: value.assigned_by_uid,
+            duration: value.duration,
+            extra: value.extra,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct V1Project {
+    id: String,
+    name: String,
+    color: Color,
+    parent_id: Option<String>,
+    child_order: u32,
+    #[serde(alias = "shared", default)]
+    is_shared: bool,
+    #[serde(default)]
+    is_favorite: bool,
+    #[serde(default)]
+    inbox_project: bool,
+    #[serde(default)]
+    team_inbox: bool,
+    view_style: ProjectViewStyle,
+    #[serde(default)]
+    is_archived: bool,
+    #[serde(default)]
+    workspace_id: Option<String>,
+    #[serde(default)]
+    folder_id: Option<String>,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ApiModel for Project {
+    type V1 = V1Project;
+
+    fn from_v1(value: V1Project) -> Project {
+        Project {
+            url: format!("{}{}", PROJECT_URL, value.id),
+            id: value.id,
+            name: value.name,
+            color: value.color,
+            parent_id: value.parent_id,
+            order: value.child_order,
+            comment_count: 0,
+            is_shared: value.is_shared,
+            is_favorite: value.is_favorite,
+            is_inbox_project: value.inbox_project,
+            is_team_inbox: value.team_inbox,
+            view_style: value.view_style,
+            is_archived: value.is_archived,
+            workspace_id: value.workspace_id,
+            folder_id: value.folder_id,
+            extra: value.extra,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct V1Section {
+    id: String,
+    project_id: String,
+    section_order: u32,
+    name: String,
+    #[serde(default)]
+    is_archived: bool,
+    #[serde(default, alias = "collapsed")]
+    is_collapsed: bool,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ApiModel for Section {
+    type V1 = V1Section;
+
+    fn from_v1(value: V1Section) -> Section {
+        Section {
+            id: value.id,
+            project_id: value.project_id,
+            order: value.section_order,
+            name: value.name,
+            is_archived: value.is_archived,
+            is_collapsed: value.is_collapsed,
+            extra: value.extra,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct V1Label {
+    id: String,
+    name: String,
+    color: Color,
+    #[serde(alias = "order")]
+    item_order: u32,
+    #[serde(default)]
+    is_favorite: bool,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ApiModel for PersonalLabel {
+    type V1 = V1Label;
+
+    fn from_v1(value: V1Label) -> PersonalLabel {
+        PersonalLabel {
+            id: value.id,
+            name: value.name,
+            color: value.color,
+            order: value.item_order,
+            is_favorite: value.is_favorite,
+            extra: value.extra,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct V1Comment {
+    id: String,
+    item_id: Option<String>,
+    project_id: Option<String>,
+    content: String,
+    posted_at: Option<String>,
+    file_attachment: Option<CommentAttachment>,
+    reactions: Option<HashMap<String, Vec<String>>>,
+    #[serde(flatten)]
+    extra: HashMap<String, serde_json::Value>,
+}
+
+impl ApiModel for Comment {
+    type V1 = V1Comment;
+
+    fn from_v1(value: V1Comment) -> Comment {
+        Comment {
+            id: value.id,
+            task_id: value.item_id,
+            project_id: value.project_id,
+            posted_at: value.posted_at.unwrap_or_default(),
+            content: value.content,
+            attachment: value.file_attachment,
+            reactions: value.reactions.unwrap_or_default(),
+            extra: value.extra,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::internal::request::TodoistAPIError;
+    use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+    use crate::todoist_config::{ApiVersion, TodoistConfig};
+
+    struct ProjectPages;
+
+    #[async_trait]
+    impl Middleware for ProjectPages {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            let (id, next_cursor) = match request.url.as_str() {
+                "https://api.todoist.com/api/v1/projects" => ("1", r#""a""#),
+                "https://api.todoist.com/api/v1/projects?cursor=a" => ("2", "null"),
+                url => panic!("unexpected URL {}", url),
+            };
+            let body = format!(
+                r#"{{"results": [{{"id": "{}", "name": "Inbox", "color": "grey", "parent_id": null,
+                    "child_order": 0, "is_shared": false, "is_favorite": false,
+                    "inbox_project": true, "view_style": "list", "is_archived": false}}],
+                    "next_cursor": {}}}"#,
+                id, next_cursor
+            );
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.into_bytes(),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_follow_cursors() {
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_api_version(ApiVersion::V1)
+            .with_middleware(ProjectPages);
+        let projects = crate::projects::get_all_projects(&config).await.unwrap();
+        assert_eq!(projects.len(), 2);
+        assert!(projects[0].is_inbox_project);
+        assert_eq!(projects[1].url, "https://app.todoist.com/app/project/2");
+    }
+
+    #[test]
+    fn task_from_v1() {
+        let task: V1Task = serde_json::from_str(
+            r#"{
+                "id": "6X7rM8997g3RQmvh",
+                "user_id": "2671355",
+                "project_id": "6Jf8VQXxpwv56VQ7",
+                "section_id": null,
+                "parent_id": null,
+                "added_by_uid": "2671355",
+                "assigned_by_uid": null,
+                "responsible_uid": null,
+                "labels": ["Food"],
+                "deadline": null,
+                "duration": {"amount": 15, "unit": "minute"},
+                "checked": true,
+                "is_deleted": false,
+                "added_at": "2024-08-01T10:00:00.000000Z",
+                "completed_at": null,
+                "updated_at": null,
+                "due": null,
+                "priority": 4,
+                "child_order": 3,
+                "content": "Buy Milk",
+                "description": "",
+                "note_count": 2,
+                "day_order": -1,
+                "is_collapsed": false
+            }"#,
+        )
+        .unwrap();
+        let task = Task::from_v1(task);
+        assert!(task.is_completed);
+        assert_eq!(task.order, 3);
+        assert_eq!(task.comment_count, 2);
+        assert_eq!(task.creator_id.as_deref(), Some("2671355"));
+        assert_eq!(
+            task.created_at.as_deref(),
+            Some("2024-08-01T10:00:00.000000Z")
+        );
+        assert_eq!(
+            task.url,
+            "https://app.todoist.com/app/task/6X7rM8997g3RQmvh"
+        );
+    }
+
+    #[test]
+    fn page_from_v1() {
+        let page: <Page<Section> as ApiModel>::V1 = serde_json::from_str(
+            r#"{
+                "results": [{
+                    "id": "6Jf8VQXxpwv56VQ7",
+                    "project_id": "6Jf8VQXxpwv56VQ8",
+                    "section_order": 2,
+                    "name": "Groceries",
+                    "is_collapsed": false
+                }],
+                "next_cursor": "abc"
+            }"#,
+        )
+        .unwrap();
+        let page = Page::<Section>::from_v1(page);
+        assert_eq!(page.results[0].order, 2);
+        assert_eq!(page.next_cursor.as_deref(), Some("abc"));
+    }
+
+    #[test]
+    fn comment_from_v1() {
+        let comment: V1Comment = serde_json::from_str(
+            r#"{
+                "id": "6X7gfQHG59V8CJJV",
+                "posted_uid": "2671355",
+                "content": "Need one bottle of milk",
+                "file_attachment": null,
+                "uids_to_notify": null,
+                "is_deleted": false,
+                "posted_at": "2016-09-22T07:00:00.000000Z",
+                "reactions": {"👍": ["2671362", "2671366"], "❤️": ["2671362"]},
+                "item_id": "6X7rM8997g3RQmvh"
+            }"#,
+        )
+        .unwrap();
+        let comment = Comment::from_v1(comment);
+        assert_eq!(comment.task_id.as_deref(), Some("6X7rM8997g3RQmvh"));
+        assert_eq!(comment.posted_at, "2016-09-22T07:00:00.000000Z");
+        assert_eq!(comment.get_reaction_count("👍"), 2);
+        assert_eq!(comment.get_reaction_count("🎉"), 0);
+    }
+}
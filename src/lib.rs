@@ -1,15 +1,41 @@
 #[doc(inline)]
 pub use api::*;
 #[doc(inline)]
+pub use client::TodoistClient;
+#[doc(inline)]
 pub use internal::request::TodoistAPIError;
 #[doc(inline)]
+pub use todoist_api::TodoistApi;
+#[doc(inline)]
 pub use todoist_config::create_config;
 
 #[warn(missing_docs)]
 mod api;
+#[cfg(feature = "blocking")]
+#[warn(missing_docs)]
+pub mod blocking;
+#[warn(missing_docs)]
+pub mod client;
+#[cfg(feature = "fake")]
+#[warn(missing_docs)]
+pub mod fake;
+#[warn(missing_docs)]
+pub mod middleware;
 #[warn(missing_docs)]
 pub mod model;
 #[warn(missing_docs)]
+pub mod oauth;
+#[warn(missing_docs)]
+pub mod secret;
+#[warn(missing_docs)]
+pub mod todoist_api;
+#[warn(missing_docs)]
 pub mod todoist_config;
+#[cfg(feature = "vcr")]
+#[warn(missing_docs)]
+pub mod vcr;
+#[cfg(feature = "webhook")]
+#[warn(missing_docs)]
+pub mod webhook;
 
 mod internal;
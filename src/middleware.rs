@@ -0,0 +1,481 @@
+//! Shared behaviour applied to every request sent with a [TodoistConfig](crate::todoist_config::TodoistConfig)
+//!
+//! * [RetryPolicy] - retries requests that failed because of rate limiting, server errors or network errors
+//! * [RateLimiter] - delays requests to stay below the Todoist API rate limit
+//! * [ResponseCache] - reuses recent responses of `GET` requests
+//! * [Middleware] - custom hooks called before each request and after each response
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use tokio::time::Instant;
+
+use crate::internal::request::TodoistAPIError;
+
+/// A request about to be sent to the Todoist API
+///
+/// The `Authorization` header is not included, as it is added right before the request is sent.
+#[derive(Debug, Clone)]
+pub struct TodoistRequest {
+    /// The HTTP method of the request
+    pub method: Method,
+    /// The full URL of the request (ex: `https://api.todoist.com/rest/v2/projects`)
+    pub url: String,
+    /// The headers of the request
+    pub headers: HeaderMap,
+    /// The body of the request (JSON, or `multipart/form-data` for uploads)
+    ///
+    /// The body is shared (not copied) when the request is cloned or retried.
+    pub body: Option<Bytes>,
+}
+
+/// A response received from the Todoist API (or created by a [Middleware])
+#[derive(Debug, Clone)]
+pub struct TodoistResponse {
+    /// The HTTP status code of the response
+    pub status: StatusCode,
+    /// The headers of the response
+    pub headers: HeaderMap,
+    /// The body of the response
+    pub body: Vec<u8>,
+}
+
+/// Hooks that are called for every request sent with a [TodoistConfig](crate::todoist_config::TodoistConfig)
+///
+/// Middleware can modify requests (ex: add tracing headers or rewrite URLs),
+/// inspect or modify responses (ex: log latency or record metrics),
+/// and answer requests without sending them (ex: serve cached responses).
+///
+/// The [retry policy](RetryPolicy), [rate limiter](RateLimiter) and [response cache](ResponseCache)
+/// are applied after all [Middleware::before_request] hooks, so the hooks are called once per request
+/// and not once per retry.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Called before the request is sent
+    ///
+    /// Returning a response skips the following middleware and sending the request,
+    /// but the [Middleware::after_response] hooks that were already reached are still called.
+    async fn before_request(
+        &self,
+        _request: &mut TodoistRequest,
+    ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+        Ok(None)
+    }
+
+    /// Called after a response is received, before its status code is checked
+    async fn after_response(
+        &self,
+        _request: &TodoistRequest,
+        _response: &mut TodoistResponse,
+    ) -> Result<(), TodoistAPIError> {
+        Ok(())
+    }
+}
+
+/// Allows keeping a handle to a [Middleware] after adding it to a config
+#[async_trait]
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    async fn before_request(
+        &self,
+        request: &mut TodoistRequest,
+    ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+        (**self).before_request(request).await
+    }
+
+    async fn after_response(
+        &self,
+        request: &TodoistRequest,
+        response: &mut TodoistResponse,
+    ) -> Result<(), TodoistAPIError> {
+        (**self).after_response(request, response).await
+    }
+}
+
+/// Retries requests that failed because of rate limiting (status code `429`),
+/// server errors (status code `5xx`) or network errors
+///
+/// Requests that may change data are only retried after a server or network error
+/// if they were sent with an `X-Request-Id`, which Todoist uses to ignore duplicates.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of times that a request is retried
+    pub max_retries: u32,
+    /// The delay before the first retry (doubled for each following retry)
+    pub initial_backoff: Duration,
+    /// The maximum delay between two retries
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Get the delay before retrying a request for the `retry`-th time (starting at `1`)
+    ///
+    /// If Todoist sent a `Retry-After` header, then that delay is used instead (up to `max_backoff`).
+    pub fn get_backoff(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or_else(|| {
+                self.initial_backoff
+                    .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            })
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Limits the number of requests sent within a time window
+///
+/// Clones of a [RateLimiter] share the same window,
+/// so one limiter can be used by multiple configs of the same user.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    max_requests: usize,
+    period: Duration,
+    sent: Arc<Mutex<VecDeque<Instant>>>,
+}
+
+impl RateLimiter {
+    /// The maximum number of requests that Todoist allows per user within [RateLimiter::TODOIST_PERIOD]
+    pub const TODOIST_MAX_REQUESTS: usize = 1000;
+    /// The time window used by the Todoist API rate limit
+    pub const TODOIST_PERIOD: Duration = Duration::from_secs(15 * 60);
+
+    /// Creates a new [RateLimiter] that allows at most `max_requests` requests within `period`
+    pub fn new(max_requests: usize, period: Duration) -> RateLimiter {
+        RateLimiter {
+            max_requests: max_requests.max(1),
+            period,
+            sent: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    /// Waits until a request can be sent without exceeding the limit, and then records it
+    pub async fn acquire(&self) {
+        loop {
+            let wait_until = {
+                let mut sent = self.sent.lock().unwrap();
+                let now = Instant::now();
+                while sent
+                    .front()
+                    .is_some_and(|sent_at| now.duration_since(*sent_at) >= self.period)
+                {
+                    sent.pop_front();
+                }
+                if sent.len() < self.max_requests {
+                    sent.push_back(now);
+                    return;
+                }
+                *sent.front().unwrap() + self.period
+            };
+            tokio::time::sleep_until(wait_until).await;
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    /// Creates a [RateLimiter] using the Todoist API limit
+    /// ([1000](RateLimiter::TODOIST_MAX_REQUESTS) requests every [15 minutes](RateLimiter::TODOIST_PERIOD))
+    fn default() -> Self {
+        RateLimiter::new(Self::TODOIST_MAX_REQUESTS, Self::TODOIST_PERIOD)
+    }
+}
+
+type CacheEntries = HashMap<String, (Instant, Vec<u8>)>;
+
+/// Caches the responses of `GET` requests for a limited time
+///
+/// Any successful request that is not a `GET` request clears the cache,
+/// so that changes are visible immediately.
+/// Clones of a [ResponseCache] share the same entries. Responses are cached per API token,
+/// so a cache shared between configs of different accounts never mixes their responses.
+///
+/// Expired responses are removed when a new response is cached, and the oldest response
+/// is removed when the cache already holds [max_entries](ResponseCache::with_max_entries) responses.
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    time_to_live: Duration,
+    max_entries: usize,
+    entries: Arc<Mutex<CacheEntries>>,
+}
+
+impl ResponseCache {
+    /// The default maximum number of cached responses
+    pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+    /// Creates a new [ResponseCache] that keeps up to [DEFAULT_MAX_ENTRIES](ResponseCache::DEFAULT_MAX_ENTRIES)
+    /// responses for `time_to_live`
+    pub fn new(time_to_live: Duration) -> ResponseCache {
+        ResponseCache {
+            time_to_live,
+            max_entries: Self::DEFAULT_MAX_ENTRIES,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Keeps at most `max_entries` responses (at least one)
+    pub fn with_max_entries(mut self, max_entries: usize) -> ResponseCache {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    /// Removes all cached responses
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(path) {
+            Some((cached_at, body)) if cached_at.elapsed() < self.time_to_live => {
+                Some(body.clone())
+            }
+            Some(_) => {
+                entries.remove(path);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(crate) fn insert(&self, path: String, body: Vec<u8>) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (cached_at, _)| cached_at.elapsed() < self.time_to_live);
+        if !entries.contains_key(&path) && entries.len() >= self.max_entries {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (cached_at, _))| *cached_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(path, (Instant::now(), body));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.get_backoff(1, None), Duration::from_millis(500));
+        assert_eq!(policy.get_backoff(3, None), Duration::from_secs(2));
+        assert_eq!(policy.get_backoff(20, None), Duration::from_secs(30));
+        assert_eq!(
+            policy.get_backoff(1, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limiter_waits_for_window() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(10));
+        let start = Instant::now();
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_secs(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_expires() {
+        let cache = ResponseCache::new(Duration::from_secs(5));
+        cache.insert("/projects".into(), b"[]".to_vec());
+        assert_eq!(cache.get("/projects"), Some(b"[]".to_vec()));
+        tokio::time::advance(Duration::from_secs(6)).await;
+        assert_eq!(cache.get("/projects"), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache_evicts_expired_and_oldest_entries() {
+        let cache = ResponseCache::new(Duration::from_secs(5)).with_max_entries(2);
+        cache.insert("/projects".into(), b"[]".to_vec());
+        tokio::time::advance(Duration::from_secs(6)).await;
+        cache.insert("/sections".into(), b"[]".to_vec());
+        assert_eq!(cache.entries.lock().unwrap().len(), 1);
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        cache.insert("/labels".into(), b"[]".to_vec());
+        tokio::time::advance(Duration::from_secs(1)).await;
+        cache.insert("/tasks".into(), b"[]".to_vec());
+        assert_eq!(cache.get("/sections"), None);
+        assert_eq!(cache.get("/labels"), Some(b"[]".to_vec()));
+        assert_eq!(cache.get("/tasks"), Some(b"[]".to_vec()));
+    }
+
+    struct TraceHeader {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for TraceHeader {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("before {}", request.url));
+            request
+                .headers
+                .insert("X-Trace-Id", "0123".parse().unwrap());
+            Ok(None)
+        }
+    }
+
+    struct CannedResponse {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Middleware for CannedResponse {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            assert_eq!(request.headers["X-Trace-Id"], "0123");
+            assert!(!request.headers.contains_key("Authorization"));
+            Ok(Some(TodoistResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: br#"[{"id": "2203306141", "name": "Shopping List", "comment_count": 0,
+                    "order": 1, "color": "charcoal", "is_shared": false, "is_favorite": false,
+                    "is_inbox_project": false, "is_team_inbox": false, "view_style": "list",
+                    "url": "https://todoist.com/showProject?id=2203306141", "parent_id": null}]"#
+                    .to_vec(),
+            }))
+        }
+
+        async fn after_response(
+            &self,
+            _request: &TodoistRequest,
+            response: &mut TodoistResponse,
+        ) -> Result<(), TodoistAPIError> {
+            self.calls.lock().unwrap().push("after".into());
+            assert_eq!(response.status, StatusCode::OK);
+            Ok(())
+        }
+    }
+
+    struct Unreachable;
+
+    #[async_trait]
+    impl Middleware for Unreachable {
+        async fn before_request(
+            &self,
+            _request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            panic!("middleware after a short-circuit should not be called")
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_can_short_circuit_requests() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let config = crate::todoist_config::TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(TraceHeader {
+                calls: calls.clone(),
+            })
+            .with_middleware(CannedResponse {
+                calls: calls.clone(),
+            })
+            .with_middleware(Unreachable);
+        let projects = crate::projects::get_all_projects(&config).await.unwrap();
+        assert_eq!(projects.len(), 1);
+        assert_eq!(projects[0].name, "Shopping List");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                "before https://api.todoist.com/rest/v2/projects".to_string(),
+                "after".to_string()
+            ]
+        );
+    }
+
+    /// Sends every request to a local server that counts the requests it receives
+    struct LocalServer {
+        address: std::net::SocketAddr,
+    }
+
+    #[async_trait]
+    impl Middleware for LocalServer {
+        async fn before_request(
+            &self,
+            request: &mut TodoistRequest,
+        ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+            request.url = request.url.replace(
+                "https://api.todoist.com",
+                &format!("http://{}", self.address),
+            );
+            Ok(None)
+        }
+    }
+
+    async fn serve_projects(requests: Arc<Mutex<usize>>) -> LocalServer {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let _ = stream.read(&mut request).await.unwrap();
+                *requests.lock().unwrap() += 1;
+                stream
+                    .write_all(
+                        b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]",
+                    )
+                    .await
+                    .unwrap();
+            }
+        });
+        LocalServer { address }
+    }
+
+    #[tokio::test]
+    async fn cached_responses_are_specific_to_the_token() {
+        let requests = Arc::new(Mutex::new(0));
+        let server = serve_projects(requests.clone()).await;
+        let cache = ResponseCache::new(Duration::from_secs(60));
+        let config = crate::todoist_config::TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_response_cache(cache.clone())
+            .with_middleware(LocalServer {
+                address: server.address,
+            });
+        crate::projects::get_all_projects(&config).await.unwrap();
+        crate::projects::get_all_projects(&config).await.unwrap();
+        assert_eq!(*requests.lock().unwrap(), 1);
+
+        let other_account = crate::todoist_config::TodoistConfig::new("fedcba9876543210")
+            .unwrap()
+            .with_response_cache(cache)
+            .with_middleware(server);
+        crate::projects::get_all_projects(&other_account)
+            .await
+            .unwrap();
+        assert_eq!(*requests.lock().unwrap(), 2);
+
+        config.set_token("fedcba9876543210").unwrap();
+        crate::projects::get_all_projects(&config).await.unwrap();
+        assert_eq!(*requests.lock().unwrap(), 3);
+    }
+}
@@ -1,6 +1,10 @@
 //! Structures representing collaborators in the Todoist API (<https://developer.todoist.com/rest/v2/?shell#get-all-collaborators>)
 
-use serde::Deserialize;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+const AVATAR_URL: &str = "https://dcff1xvirvpfp.cloudfront.net/";
 
 /// A structure representing a collaborator
 #[derive(Debug, Deserialize, Clone)]
@@ -8,7 +12,69 @@ pub struct Collaborator {
     /// The collaborator's ID (ex: "1234567")
     pub id: String,
     /// The collaborator's name (ex: "Bob")
+    #[serde(alias = "full_name")]
     pub name: String,
     /// The collaborator's email address (ex: "bob@example.com")
     pub email: String,
+    /// The collaborator's role in the project (only set for projects in a workspace,
+    /// and only returned by [crate::projects::get_collaborators_with_roles])
+    #[serde(default)]
+    pub role: Option<CollaboratorRole>,
+    /// The ID of the collaborator's avatar ([None] if the collaborator has no avatar)
+    #[serde(default)]
+    pub image_id: Option<String>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Collaborator {
+    /// Get the URL of the collaborator's avatar in the given size ([None] if the collaborator has no avatar)
+    pub fn get_avatar_url(&self, size: AvatarSize) -> Option<String> {
+        let size = match size {
+            AvatarSize::Small => "small",
+            AvatarSize::Medium => "medium",
+            AvatarSize::Big => "big",
+            AvatarSize::S640 => "s640",
+        };
+        self.image_id
+            .as_ref()
+            .map(|image_id| format!("{}{}_{}.jpg", AVATAR_URL, image_id, size))
+    }
+}
+
+/// The role of a [Collaborator] in a project of a workspace
+///
+/// Roles added to Todoist after this version are deserialized as [CollaboratorRole::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum CollaboratorRole {
+    /// The creator of the project
+    Creator,
+    /// Can manage the project and its collaborators
+    Admin,
+    /// Can add, edit and complete tasks
+    ReadWrite,
+    /// Can only read and comment
+    ReadAndComment,
+    /// Can only read
+    ReadOnly,
+    /// A role that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The size of a [Collaborator]'s avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarSize {
+    /// 35x35 pixels
+    Small,
+    /// 60x60 pixels
+    Medium,
+    /// 195x195 pixels
+    Big,
+    /// 640x640 pixels
+    S640,
 }
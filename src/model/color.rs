@@ -4,14 +4,20 @@
 //! and [projects](crate::model::project::Project)
 
 use fmt::Display;
+use std::error::Error;
 use std::fmt;
 use std::fmt::Formatter;
+use std::str::FromStr;
 
+use serde::de::value::{Error as ValueError, StrDeserializer};
 use serde::{Deserialize, Serialize};
 
 /// An enum of the colors used in the Todoist API
+///
+/// Colors added to Todoist after this version are deserialized as [Color::Unknown].
 #[allow(missing_docs)]
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum Color {
     #[serde(rename = "berry_red")]
     BerryRed,
@@ -53,85 +59,161 @@ pub enum Color {
     Grey,
     #[serde(rename = "taupe")]
     Taupe,
+    /// A color that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Display for Color {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Color::Unknown(name) => write!(f, "{}", name),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses the API name (ex: `berry_red`), the name printed by [Display] (ex: `BerryRed`)
+    /// or the hexadecimal form (ex: `#B8256F`) of a [Color]
+    ///
+    /// Unknown names are rejected rather than parsed as [Color::Unknown].
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.starts_with('#') {
+            return Color::from_hex(value);
+        }
+        match Color::deserialize(StrDeserializer::<ValueError>::new(value)) {
+            Ok(Color::Unknown(_)) | Err(_) => Color::ALL
+                .into_iter()
+                .find(|color| color.to_string() == value)
+                .ok_or_else(|| ParseColorError {
+                    value: value.to_string(),
+                }),
+            Ok(color) => Ok(color),
+        }
+    }
+}
+
+impl TryFrom<u8> for Color {
+    type Error = ParseColorError;
+
+    /// Get the [Color] with the given Todoist API ID (see [Color::get_id])
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Color::ALL
+            .into_iter()
+            .find(|color| color.get_id() == Some(value))
+            .ok_or_else(|| ParseColorError {
+                value: value.to_string(),
+            })
+    }
+}
+
+impl TryFrom<&ColorHex> for Color {
+    type Error = ParseColorError;
+
+    fn try_from(value: &ColorHex) -> Result<Self, Self::Error> {
+        Color::from_hex(&value.hex)
     }
 }
 
 impl Color {
+    /// All of the known colors, in the order of their IDs
+    pub const ALL: [Color; 20] = [
+        Color::BerryRed,
+        Color::Red,
+        Color::Orange,
+        Color::Yellow,
+        Color::OliveGreen,
+        Color::LimeGreen,
+        Color::Green,
+        Color::MintGreen,
+        Color::Teal,
+        Color::SkyBlue,
+        Color::LightBlue,
+        Color::Blue,
+        Color::Grape,
+        Color::Violet,
+        Color::Lavender,
+        Color::Magenta,
+        Color::Salmon,
+        Color::Charcoal,
+        Color::Grey,
+        Color::Taupe,
+    ];
+
+    /// Get the [Color] with exactly the given hexadecimal form (ex: `#B8256F`, case insensitive)
+    ///
+    /// Use [Color::nearest] to find the closest color to any other color.
+    pub fn from_hex(hex: &str) -> Result<Color, ParseColorError> {
+        let rgb: Rgb = hex.parse()?;
+        Color::ALL
+            .into_iter()
+            .find(|color| color.get_rgb() == Some(rgb))
+            .ok_or_else(|| ParseColorError {
+                value: hex.to_string(),
+            })
+    }
+
+    /// Get the [Color] that looks the closest to `rgb`
+    ///
+    /// Colors are compared with a weighted Euclidean distance (the "redmean" approximation),
+    /// which is closer to how colors are perceived than the plain distance between RGB values.
+    pub fn nearest(rgb: Rgb) -> Color {
+        Color::ALL
+            .into_iter()
+            .min_by_key(|color| {
+                color
+                    .get_rgb()
+                    .map_or(u64::MAX, |color_rgb| color_rgb.distance(rgb))
+            })
+            .unwrap_or(Color::Charcoal)
+    }
+
+    /// Get the [Rgb] form of the [Color] ([None] for [Unknown](Color::Unknown) colors)
+    pub fn get_rgb(&self) -> Option<Rgb> {
+        self.get_hex().map(|hex| {
+            hex.hex
+                .parse()
+                .expect("the hexadecimal forms of the colors are valid")
+        })
+    }
+
     //noinspection SpellCheckingInspection
-    /// Get the hexadecimal form of the [Color]
-    pub fn get_hex(&self) -> ColorHex {
-        match self {
-            Color::BerryRed => ColorHex {
-                hex: String::from("#B8256F"),
-            },
-            Color::Red => ColorHex {
-                hex: String::from("#DB4035"),
-            },
-            Color::Orange => ColorHex {
-                hex: String::from("#FF9933"),
-            },
-            Color::Yellow => ColorHex {
-                hex: String::from("#FAD000"),
-            },
-            Color::OliveGreen => ColorHex {
-                hex: String::from("#AFB83B"),
-            },
-            Color::LimeGreen => ColorHex {
-                hex: String::from("#7EBC00"),
-            },
-            Color::Green => ColorHex {
-                hex: String::from("#299438"),
-            },
-            Color::MintGreen => ColorHex {
-                hex: String::from("#6ACCBC"),
-            },
-            Color::Teal => ColorHex {
-                hex: String::from("#158FAD"),
-            },
-            Color::SkyBlue => ColorHex {
-                hex: String::from("#14AAF5"),
-            },
-            Color::LightBlue => ColorHex {
-                hex: String::from("#96C3EB"),
-            },
-            Color::Blue => ColorHex {
-                hex: String::from("#4073FF"),
-            },
-            Color::Grape => ColorHex {
-                hex: String::from("#884DFF"),
-            },
-            Color::Violet => ColorHex {
-                hex: String::from("#AF38EB"),
-            },
-            Color::Lavender => ColorHex {
-                hex: String::from("#EB96EB"),
-            },
-            Color::Magenta => ColorHex {
-                hex: String::from("#E05194"),
-            },
-            Color::Salmon => ColorHex {
-                hex: String::from("#FF8D85"),
-            },
-            Color::Charcoal => ColorHex {
-                hex: String::from("#808080"),
-            },
-            Color::Grey => ColorHex {
-                hex: String::from("#B8B8B8"),
-            },
-            Color::Taupe => ColorHex {
-                hex: String::from("#CCAC93"),
-            },
-        }
+    /// Get the hexadecimal form of the [Color] ([None] for [Unknown](Color::Unknown) colors)
+    pub fn get_hex(&self) -> Option<ColorHex> {
+        let hex = match self {
+            Color::BerryRed => "#B8256F",
+            Color::Red => "#DB4035",
+            Color::Orange => "#FF9933",
+            Color::Yellow => "#FAD000",
+            Color::OliveGreen => "#AFB83B",
+            Color::LimeGreen => "#7EBC00",
+            Color::Green => "#299438",
+            Color::MintGreen => "#6ACCBC",
+            Color::Teal => "#158FAD",
+            Color::SkyBlue => "#14AAF5",
+            Color::LightBlue => "#96C3EB",
+            Color::Blue => "#4073FF",
+            Color::Grape => "#884DFF",
+            Color::Violet => "#AF38EB",
+            Color::Lavender => "#EB96EB",
+            Color::Magenta => "#E05194",
+            Color::Salmon => "#FF8D85",
+            Color::Charcoal => "#808080",
+            Color::Grey => "#B8B8B8",
+            Color::Taupe => "#CCAC93",
+            Color::Unknown(_) => return None,
+        };
+        Some(ColorHex {
+            hex: String::from(hex),
+        })
     }
 
-    /// Get the Todoist API ID of the [Color]
-    pub fn get_id(&self) -> u8 {
-        match self {
+    /// Get the Todoist API ID of the [Color] ([None] for [Unknown](Color::Unknown) colors)
+    pub fn get_id(&self) -> Option<u8> {
+        let id = match self {
             Color::BerryRed => 30,
             Color::Red => 31,
             Color::Orange => 32,
@@ -152,7 +234,9 @@ impl Color {
             Color::Charcoal => 47,
             Color::Grey => 48,
             Color::Taupe => 49,
-        }
+            Color::Unknown(_) => return None,
+        };
+        Some(id)
     }
 }
 
@@ -169,8 +253,141 @@ impl Display for ColorHex {
     }
 }
 
-impl From<Color> for ColorHex {
-    fn from(value: Color) -> Self {
-        value.get_hex()
+impl TryFrom<Color> for ColorHex {
+    type Error = ParseColorError;
+
+    /// Get the hexadecimal form of a [Color], which fails for [Unknown](Color::Unknown) colors
+    fn try_from(value: Color) -> Result<Self, Self::Error> {
+        value.get_hex().ok_or_else(|| ParseColorError {
+            value: value.to_string(),
+        })
+    }
+}
+
+/// The red, green and blue components of a color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// The squared "redmean" distance between two colors (multiplied by 256 to stay an integer)
+    fn distance(self, other: Rgb) -> u64 {
+        let red_mean = (self.0 as i64 + other.0 as i64) / 2;
+        let red = self.0 as i64 - other.0 as i64;
+        let green = self.1 as i64 - other.1 as i64;
+        let blue = self.2 as i64 - other.2 as i64;
+        ((512 + red_mean) * red * red + 1024 * green * green + (767 - red_mean) * blue * blue)
+            as u64
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Rgb(red, green, blue)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ParseColorError;
+
+    /// Parses a hexadecimal color, with or without a leading `#` (ex: `#B8256F` or `b8256f`)
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorError {
+            value: value.to_string(),
+        };
+        let hex = value.strip_prefix('#').unwrap_or(value);
+        // `from_str_radix` accepts a leading `+`, so every character is checked first
+        if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let component = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16);
+        match (component(0), component(2), component(4)) {
+            (Ok(red), Ok(green), Ok(blue)) => Ok(Rgb(red, green, blue)),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.0, self.1, self.2)
+    }
+}
+
+impl ColorHex {
+    /// Get the [Rgb] form of the hexadecimal value
+    pub fn to_rgb(&self) -> Result<Rgb, ParseColorError> {
+        self.hex.parse()
+    }
+}
+
+/// An error returned when a value is not a valid [Color] or [Rgb] color
+#[derive(Debug, Clone)]
+pub struct ParseColorError {
+    /// The value that could not be parsed
+    pub value: String,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid color - {}", self.value)
+    }
+}
+
+impl Error for ParseColorError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_round_trip() {
+        for color in Color::ALL {
+            let hex = color.get_hex().unwrap();
+            assert_eq!(Color::try_from(color.get_id().unwrap()).unwrap(), color);
+            assert_eq!(hex.hex.parse::<Color>().unwrap(), color);
+            assert_eq!(Color::try_from(&hex).unwrap(), color);
+            assert_eq!(color.get_rgb().unwrap().to_string(), hex.hex);
+            assert_eq!(Color::nearest(color.get_rgb().unwrap()), color);
+            assert_eq!(color.to_string().parse::<Color>().unwrap(), color);
+        }
+        assert_eq!("berry_red".parse::<Color>().unwrap(), Color::BerryRed);
+        assert_eq!("BerryRed".parse::<Color>().unwrap(), Color::BerryRed);
+        assert_eq!(Color::from_hex("#b8256f").unwrap(), Color::BerryRed);
+    }
+
+    #[test]
+    fn invalid_colors_are_rejected() {
+        assert!(Color::try_from(29).is_err());
+        assert!("berryred".parse::<Color>().is_err());
+        assert!(Color::from_hex("#B8256E").is_err());
+        assert!("ultraviolet".parse::<Color>().is_err());
+        assert!("#B8256".parse::<Rgb>().is_err());
+        assert!("#B8256G".parse::<Rgb>().is_err());
+        assert!("#+F+F+F".parse::<Rgb>().is_err());
+        assert_eq!(
+            "#éB825".parse::<Rgb>().unwrap_err().to_string(),
+            "Invalid color - #éB825"
+        );
+    }
+
+    #[test]
+    fn nearest_colors() {
+        assert_eq!(Color::nearest(Rgb(255, 0, 0)), Color::Red);
+        assert_eq!(Color::nearest(Rgb(255, 255, 255)), Color::Grey);
+        assert_eq!(Color::nearest("#1DA1F2".parse().unwrap()), Color::SkyBlue);
+    }
+
+    #[test]
+    fn unknown_colors_round_trip() {
+        let color: Color = serde_json::from_str(r#""ultraviolet""#).unwrap();
+        assert_eq!(color, Color::Unknown("ultraviolet".into()));
+        assert_eq!(serde_json::to_string(&color).unwrap(), r#""ultraviolet""#);
+        assert_eq!(color.to_string(), "ultraviolet");
+        assert!(color.get_hex().is_none());
+        assert!(color.get_id().is_none());
+        assert!(color.get_rgb().is_none());
+        assert!(ColorHex::try_from(color).is_err());
+        let color: Color = serde_json::from_str(r#""berry_red""#).unwrap();
+        assert_eq!(color, Color::BerryRed);
     }
 }
@@ -1,5 +1,7 @@
 //! Structures representing objects in the Todoist Comments API (<https://developer.todoist.com/rest/v2/?shell#comments>)
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// A Todoist comment (<https://developer.todoist.com/rest/v2/?shell#comments>)
@@ -13,10 +15,27 @@ pub struct Comment {
     /// The comment's [project](crate::model::project::Project) ID
     /// (will be [None] if the comment belongs to a task)
     pub project_id: Option<String>,
+    /// The date when the comment was posted (read-only)
+    pub posted_at: String,
     /// The comment's content which may contain [markdown-formatted text and hyperlinks](https://todoist.com/help/articles/205195102)
     pub content: String,
     /// The attachment file (will be [None] if there is no attachment)
     pub attachment: Option<CommentAttachment>,
+    /// The reactions to the comment, mapping each reaction (ex: `👍`) to the IDs of the users
+    /// who reacted with it (read-only, only returned by API v1)
+    #[serde(default)]
+    pub reactions: HashMap<String, Vec<String>>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Comment {
+    /// Get the number of users who reacted to the comment with `reaction` (ex: `👍`)
+    pub fn get_reaction_count(&self, reaction: &str) -> usize {
+        self.reactions.get(reaction).map_or(0, Vec::len)
+    }
 }
 
 // TODO - figure out what attributes go here
@@ -29,6 +48,46 @@ pub struct CommentAttachment {
     pub file_type: String,
     /// The attachment's URL
     pub file_url: String,
-    /// The attachment's resource type (e.g. `file`)
-    pub resource_type: String,
+    /// The attachment's resource type
+    pub resource_type: AttachmentResourceType,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The kind of resource that a [CommentAttachment] points to
+///
+/// Resource types added to Todoist after this version are deserialized as [AttachmentResourceType::Unknown].
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AttachmentResourceType {
+    /// An uploaded file
+    File,
+    /// A link to a web page
+    Url,
+    /// An uploaded image
+    Image,
+    /// An uploaded video
+    Video,
+    /// An uploaded audio file
+    Audio,
+    /// A resource type that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_resource_types_are_kept() {
+        let resource_type: AttachmentResourceType = serde_json::from_str(r#""image""#).unwrap();
+        assert_eq!(resource_type, AttachmentResourceType::Image);
+        let resource_type: AttachmentResourceType = serde_json::from_str(r#""pdf""#).unwrap();
+        assert_eq!(resource_type, AttachmentResourceType::Unknown("pdf".into()));
+        assert_eq!(serde_json::to_string(&resource_type).unwrap(), r#""pdf""#);
+    }
 }
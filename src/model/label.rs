@@ -1,5 +1,7 @@
 //! Structures representing objects in the Todoist Labels API (<https://developer.todoist.com/rest/v2/?shell#labels>)
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::internal::request::TodoistAPIError;
@@ -19,6 +21,10 @@ pub struct PersonalLabel {
     pub order: u32,
     /// Whether the label is a favorite
     pub is_favorite: bool,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl PersonalLabel {
@@ -5,5 +5,7 @@ pub mod color;
 pub mod comment;
 pub mod label;
 pub mod project;
+pub mod reminder;
 pub mod section;
 pub mod task;
+pub mod workspace;
@@ -1,9 +1,12 @@
 //! Structures and enums representing objects in the Todoist Projects API (<https://developer.todoist.com/rest/v2/?shell#projects>)
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::comments::TaskOrProjectID;
 use crate::internal::request::TodoistAPIError;
+use crate::model::collaborator::Collaborator;
 use crate::model::color::Color;
 use crate::model::comment::{Comment, CommentAttachment};
 use crate::todoist_config::TodoistConfig;
@@ -33,11 +36,31 @@ pub struct Project {
     pub is_team_inbox: bool,
     /// The way the project is displayed within the Todoist clients
     pub view_style: ProjectViewStyle,
+    /// Whether the project is archived (read-only)
+    #[serde(default)]
+    pub is_archived: bool,
+    /// The ID of the [Workspace](crate::model::workspace::Workspace) of the project
+    /// ([None] for personal projects, and always [None] with API v2)
+    #[serde(default)]
+    pub workspace_id: Option<String>,
+    /// The ID of the folder of the project in its workspace ([None] if the project is not in a folder)
+    #[serde(default)]
+    pub folder_id: Option<String>,
     /// The URL to access this project in the Todoist web or mobile applications
     pub url: String,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Project {
+    /// Whether the project belongs to a [Workspace](crate::model::workspace::Workspace)
+    /// rather than to the personal projects of a user
+    pub fn is_workspace_project(&self) -> bool {
+        self.workspace_id.is_some()
+    }
+
     /// Creates a new copy of this [Project] with a new ID from the Todoist API
     ///
     /// This method is a shortcut for [`todoist_rest_api::projects::create_new_project(config, project.name, project.parent_id, Some(project.color), Some(project.is_favorite), Some(project.view_style))`](crate::projects::create_new_project)
@@ -92,6 +115,30 @@ impl Project {
         crate::projects::delete_project(config, self.id).await
     }
 
+    /// Archives this project in Todoist
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::projects::archive_project(config, project.id)`](crate::projects::archive_project)
+    pub async fn archive(self, config: &TodoistConfig) -> Result<Project, TodoistAPIError> {
+        crate::projects::archive_project(config, self.id).await
+    }
+
+    /// Unarchives this project in Todoist
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::projects::unarchive_project(config, project.id)`](crate::projects::unarchive_project)
+    pub async fn unarchive(self, config: &TodoistConfig) -> Result<Project, TodoistAPIError> {
+        crate::projects::unarchive_project(config, self.id).await
+    }
+
+    /// Get all of the [collaborators](Collaborator) of this project, including their roles
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::projects::get_collaborators_with_roles(config, project.id)`](crate::projects::get_collaborators_with_roles)
+    pub async fn collaborators(
+        self,
+        config: &TodoistConfig,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        crate::projects::get_collaborators_with_roles(config, self.id).await
+    }
+
     /// Get all of the [comments](Comment) for this project
     ///
     /// If the project no longer exists, then a [TodoistAPIError] will be returned.
@@ -108,25 +155,30 @@ impl Project {
     ///
     /// If the project no longer exists, then a [TodoistAPIError] will be returned.
     ///
-    /// This method is a shortcut for [`todoist_rest_api::comments::create_new_comment(config, &TaskOrProjectID::Project(project.id), content, attachment)`](crate::comments::create_new_comment)
+    /// This method is a shortcut for [`todoist_rest_api::comments::create_new_comment(config, &TaskOrProjectID::Project(project.id), content, attachment, uids_to_notify)`](crate::comments::create_new_comment)
     pub async fn create_new_comment(
         self,
         config: &TodoistConfig,
         content: String,
         attachment: Option<CommentAttachment>,
+        uids_to_notify: Option<Vec<String>>,
     ) -> Result<Comment, TodoistAPIError> {
         crate::comments::create_new_comment(
             config,
             &TaskOrProjectID::Project(self.id),
             content,
             attachment,
+            uids_to_notify,
         )
         .await
     }
 }
 
 /// The way that the user views the project in the Todoist clients
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// View styles added to Todoist after this version are deserialized as [ProjectViewStyle::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum ProjectViewStyle {
     /// The project is displayed as a list
     #[serde(rename = "list")]
@@ -134,4 +186,34 @@ pub enum ProjectViewStyle {
     /// The project is displayed as a board
     #[serde(rename = "board")]
     Board,
+    /// The project is displayed as a calendar
+    #[serde(rename = "calendar")]
+    Calendar,
+    /// A view style that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_view_styles_and_fields_are_kept() {
+        let project: Project = serde_json::from_str(
+            r#"{"id": "1", "name": "Inbox", "color": "ultraviolet", "parent_id": null, "order": 0,
+                "comment_count": 0, "is_shared": false, "is_favorite": false,
+                "is_inbox_project": true, "is_team_inbox": false, "view_style": "calendar",
+                "url": "https://todoist.com/showProject?id=1", "description": "Everything"}"#,
+        )
+        .unwrap();
+        assert_eq!(project.view_style, ProjectViewStyle::Calendar);
+        assert_eq!(project.color, Color::Unknown("ultraviolet".into()));
+        assert_eq!(project.extra["description"], "Everything");
+        assert!(!project.extra.contains_key("view_style"));
+
+        let view_style: ProjectViewStyle = serde_json::from_str(r#""timeline""#).unwrap();
+        assert_eq!(view_style, ProjectViewStyle::Unknown("timeline".into()));
+        assert_eq!(serde_json::to_string(&view_style).unwrap(), r#""timeline""#);
+    }
 }
@@ -0,0 +1,204 @@
+//! Structures and enums representing reminders in the Todoist Sync API (<https://developer.todoist.com/api/v1/#tag/Sync/Reminders>)
+
+use std::collections::HashMap;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::model::task::TaskDueDateTime;
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
+/// A Todoist reminder of a [Task](crate::model::task::Task)
+#[derive(Debug, Clone)]
+pub struct Reminder {
+    /// The reminder ID
+    pub id: String,
+    /// The ID of the [Task](crate::model::task::Task) that the reminder belongs to
+    ///
+    /// This is always the API v1 ID of the task, which differs from its ID in API v2.
+    pub task_id: String,
+    /// The ID of the user to notify (will be [None] to notify the current user)
+    pub notify_uid: Option<String>,
+    /// When the reminder is triggered
+    pub reminder_type: ReminderType,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct ReminderResponse {
+    id: String,
+    item_id: String,
+    notify_uid: Option<String>,
+    #[serde(flatten)]
+    fields: HashMap<String, serde_json::Value>,
+}
+
+impl<'de> Deserialize<'de> for Reminder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let response = ReminderResponse::deserialize(deserializer)?;
+        let mut extra = response.fields;
+        let reminder_type = match ReminderType::deserialize(serde_json::Value::Object(
+            extra.clone().into_iter().collect(),
+        )) {
+            Ok(reminder_type) => reminder_type,
+            // A single reminder of an unknown type must not prevent reading the other reminders
+            Err(error) => match extra.get("type") {
+                Some(serde_json::Value::String(name)) => ReminderType::Unknown(name.clone()),
+                _ => return Err(D::Error::custom(error)),
+            },
+        };
+        // The fields of the reminder type are read from the same object, so they are not extra fields
+        // (the fields of an unknown reminder type are all kept in `extra`)
+        if let Ok(serde_json::Value::Object(type_fields)) = serde_json::to_value(&reminder_type) {
+            extra.retain(|key, _| !type_fields.contains_key(key));
+        }
+        Ok(Reminder {
+            id: response.id,
+            task_id: response.item_id,
+            notify_uid: response.notify_uid,
+            reminder_type,
+            extra,
+        })
+    }
+}
+
+/// When a [Reminder] is triggered
+///
+/// Reminder types added to Todoist after this version are deserialized as [ReminderType::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ReminderType {
+    /// A reminder triggered before the due date of the task
+    Relative {
+        /// The number of minutes before the due date of the task
+        minute_offset: u32,
+    },
+    /// A reminder triggered at a specific date and time
+    Absolute {
+        /// The date and time of the reminder
+        due: TaskDueDateTime,
+    },
+    /// A reminder triggered when arriving at or leaving a location
+    Location {
+        /// The name of the location
+        name: String,
+        /// The latitude of the location
+        #[serde(rename = "loc_lat")]
+        latitude: String,
+        /// The longitude of the location
+        #[serde(rename = "loc_long")]
+        longitude: String,
+        /// Whether the reminder is triggered when arriving at or leaving the location
+        #[serde(rename = "loc_trigger")]
+        trigger: LocationTrigger,
+        /// The radius around the location in meters
+        radius: u32,
+    },
+    /// A reminder type that is not known by this version (or whose fields could not be read),
+    /// with its name in the Todoist API
+    ///
+    /// The fields of the reminder are kept in [Reminder::extra].
+    /// This type cannot be used to create or update a reminder.
+    #[serde(skip)]
+    Unknown(String),
+}
+
+/// When a [location reminder](ReminderType::Location) is triggered
+///
+/// Triggers added to Todoist after this version are deserialized as [LocationTrigger::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LocationTrigger {
+    /// When arriving at the location
+    OnEnter,
+    /// When leaving the location
+    OnLeave,
+    /// A trigger that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl Reminder {
+    /// Deletes the reminder using the Todoist API.
+    ///
+    /// If the reminder no longer exists, then a [TodoistAPIError] will be returned.
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::reminders::delete_reminder(config, reminder.id)`](crate::reminders::delete_reminder)
+    pub async fn delete(self, config: &TodoistConfig) -> Result<(), TodoistAPIError> {
+        crate::reminders::delete_reminder(config, self.id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reminder_types() {
+        let reminders: Vec<Reminder> = serde_json::from_str(
+            r#"[
+                {"id": "1", "notify_uid": "2671355", "item_id": "6X7rM8997g3RQmvh",
+                 "type": "relative", "minute_offset": 30, "is_deleted": false},
+                {"id": "2", "notify_uid": null, "item_id": "6X7rM8997g3RQmvh", "type": "absolute",
+                 "due": {"date": "2016-08-05T07:00:00Z", "timezone": null, "is_recurring": false,
+                         "string": "tomorrow at 10:00", "lang": "en"}},
+                {"id": "3", "notify_uid": "2671355", "item_id": "6X7rM8997g3RQmvh",
+                 "type": "location", "name": "Aliados", "loc_lat": "41.148581",
+                 "loc_long": "-8.610945000000015", "loc_trigger": "on_enter", "radius": 100}
+            ]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            reminders[0].reminder_type,
+            ReminderType::Relative { minute_offset: 30 }
+        ));
+        match &reminders[1].reminder_type {
+            ReminderType::Absolute { due } => {
+                assert_eq!(due.string, "tomorrow at 10:00");
+                assert_eq!(due.extra["lang"], "en");
+            }
+            reminder_type => panic!("unexpected type {:?}", reminder_type),
+        }
+        assert!(reminders[1].notify_uid.is_none());
+        assert_eq!(
+            reminders[0].extra.keys().collect::<Vec<_>>(),
+            ["is_deleted"]
+        );
+        match &reminders[2].reminder_type {
+            ReminderType::Location { trigger, .. } => {
+                assert_eq!(*trigger, LocationTrigger::OnEnter)
+            }
+            reminder_type => panic!("unexpected type {:?}", reminder_type),
+        }
+    }
+
+    #[test]
+    fn unknown_reminder_types_are_kept() {
+        let reminders: Vec<Reminder> = serde_json::from_str(
+            r#"[
+                {"id": "1", "notify_uid": null, "item_id": "6X7rM8997g3RQmvh",
+                 "type": "weather", "condition": "rain"},
+                {"id": "2", "notify_uid": null, "item_id": "6X7rM8997g3RQmvh",
+                 "type": "location", "name": "Aliados", "loc_lat": "41.148581",
+                 "loc_long": "-8.610945000000015", "loc_trigger": "on_stay", "radius": 100}
+            ]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            &reminders[0].reminder_type,
+            ReminderType::Unknown(name) if name == "weather"
+        ));
+        assert_eq!(reminders[0].extra["condition"], "rain");
+        match &reminders[1].reminder_type {
+            ReminderType::Location { trigger, .. } => {
+                assert_eq!(*trigger, LocationTrigger::Unknown("on_stay".into()))
+            }
+            reminder_type => panic!("unexpected type {:?}", reminder_type),
+        }
+    }
+}
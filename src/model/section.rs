@@ -1,5 +1,7 @@
 //! Structures representing objects in the Todoist Sections API (<https://developer.todoist.com/rest/v2/?shell#sections>)
 
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
 use crate::todoist_config::TodoistConfig;
@@ -16,6 +18,16 @@ pub struct Section {
     pub order: u32,
     /// The section name
     pub name: String,
+    /// Whether the section is archived (read-only)
+    #[serde(default)]
+    pub is_archived: bool,
+    /// Whether the section's tasks are collapsed (read-only)
+    #[serde(default, alias = "collapsed")]
+    pub is_collapsed: bool,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl Section {
@@ -56,4 +68,29 @@ impl Section {
     pub async fn delete(self, config: &TodoistConfig) -> Result<(), TodoistAPIError> {
         crate::sections::delete_section(config, self.id).await
     }
+
+    /// Archives the section using the Todoist API.
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::sections::archive_section(config, section.id)`](crate::sections::archive_section)
+    pub async fn archive(self, config: &TodoistConfig) -> Result<(), TodoistAPIError> {
+        crate::sections::archive_section(config, self.id).await
+    }
+
+    /// Unarchives the section using the Todoist API.
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::sections::unarchive_section(config, section.id)`](crate::sections::unarchive_section)
+    pub async fn unarchive(self, config: &TodoistConfig) -> Result<(), TodoistAPIError> {
+        crate::sections::unarchive_section(config, self.id).await
+    }
+
+    /// Moves the section and its tasks to another [Project](crate::model::project::Project) using the Todoist API.
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::sections::move_section(config, section.id, project_id)`](crate::sections::move_section)
+    pub async fn move_to_project(
+        self,
+        config: &TodoistConfig,
+        project_id: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::sections::move_section(config, self.id, project_id).await
+    }
 }
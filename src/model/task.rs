@@ -1,5 +1,6 @@
 //! Structures and enums representing objects in the Todoist Task API (<https://developer.todoist.com/rest/v2/?shell#tasks>)
 
+use std::collections::HashMap;
 use std::time::Duration;
 
 use chrono::FixedOffset;
@@ -7,6 +8,10 @@ use chrono::{DateTime, TimeZone};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
+use crate::model::reminder::Reminder;
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
 /// A Todoist task
 #[derive(Deserialize, Debug, Clone)]
 pub struct Task {
@@ -36,16 +41,32 @@ pub struct Task {
     pub url: String,
     /// The number of task comments (read-only)
     pub comment_count: u32,
-    /// The date when the task was created (read-only)
-    pub created_at: String,
-    /// The ID of the user who created the task (read-only)
-    pub creator_id: String,
+    /// The date when the task was created (read-only, may be [None] with API v1)
+    pub created_at: Option<String>,
+    /// The ID of the user who created the task (read-only, may be [None] with API v1)
+    pub creator_id: Option<String>,
     /// The responsible user ID (will be [None] if the task is unassigned)
     pub assignee_id: Option<String>,
     /// The ID of the user who assigned the task (read-only, will be [None] if the task is unassigned)
     pub assigner_id: Option<String>,
     /// The task's duration or [None] if the task has no duration
     pub duration: Option<TaskDuration>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Task {
+    /// Retrieves the [reminders](Reminder) of this task from the Todoist API
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::reminders::get_task_reminders(config, task.id)`](crate::reminders::get_task_reminders)
+    pub async fn reminders(
+        &self,
+        config: &TodoistConfig,
+    ) -> Result<Vec<Reminder>, TodoistAPIError> {
+        crate::reminders::get_task_reminders(config, self.id.clone()).await
+    }
 }
 
 /// A structure that stores a task's due date and time
@@ -66,6 +87,10 @@ pub struct TaskDueDateTime {
     /// (i.e. "UTC-01:00")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timezone: Option<String>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 impl TaskDueDateTime {
@@ -120,25 +145,34 @@ pub struct TaskDuration {
 
 impl TaskDuration {
     /// Get the duration of a task as a [Duration]
-    pub fn get_duration(&self) -> Duration {
+    ///
+    /// Returns [None] if the [unit](TaskDurationUnit::Unknown) of the duration is not known by this version.
+    pub fn get_duration(&self) -> Option<Duration> {
         match self.unit {
-            TaskDurationUnit::Minute => Duration::from_secs(self.amount * 60),
-            TaskDurationUnit::Day => Duration::from_secs(self.amount * 60 * 60 * 24),
+            TaskDurationUnit::Minute => Some(Duration::from_secs(self.amount * 60)),
+            TaskDurationUnit::Day => Some(Duration::from_secs(self.amount * 60 * 60 * 24)),
+            TaskDurationUnit::Unknown(_) => None,
         }
     }
 
     /// Get the duration of a task as a [chrono::Duration]
+    ///
+    /// Returns [None] if the [unit](TaskDurationUnit::Unknown) of the duration is not known by this version.
     //#[cfg(feature = "chrono")]
-    pub fn get_chrono_duration(&self) -> chrono::Duration {
+    pub fn get_chrono_duration(&self) -> Option<chrono::Duration> {
         match self.unit {
-            TaskDurationUnit::Minute => chrono::Duration::minutes(self.amount as i64),
-            TaskDurationUnit::Day => chrono::Duration::days(self.amount as i64),
+            TaskDurationUnit::Minute => Some(chrono::Duration::minutes(self.amount as i64)),
+            TaskDurationUnit::Day => Some(chrono::Duration::days(self.amount as i64)),
+            TaskDurationUnit::Unknown(_) => None,
         }
     }
 }
 
 /// An enum representing the unit of time for a [TaskDuration]
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// Units added to Todoist after this version are deserialized as [TaskDurationUnit::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TaskDurationUnit {
     /// A duration in minutes
     #[serde(rename = "minute")]
@@ -146,4 +180,28 @@ pub enum TaskDurationUnit {
     /// A duration in days
     #[serde(rename = "day")]
     Day,
+    /// A unit that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_duration_units_have_no_duration() {
+        let duration: TaskDuration =
+            serde_json::from_str(r#"{"amount": 2, "unit": "day"}"#).unwrap();
+        assert_eq!(duration.get_duration(), Some(Duration::from_secs(172_800)));
+        assert_eq!(
+            duration.get_chrono_duration(),
+            Some(chrono::Duration::days(2))
+        );
+        let duration: TaskDuration =
+            serde_json::from_str(r#"{"amount": 2, "unit": "week"}"#).unwrap();
+        assert_eq!(duration.unit, TaskDurationUnit::Unknown("week".into()));
+        assert_eq!(duration.get_duration(), None);
+        assert_eq!(duration.get_chrono_duration(), None);
+    }
 }
@@ -0,0 +1,115 @@
+//! Structures and enums representing workspaces in the Todoist Sync API (<https://developer.todoist.com/api/v1/#tag/Sync/Workspace>)
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::project::Project;
+use crate::todoist_config::TodoistConfig;
+use crate::TodoistAPIError;
+
+/// A Todoist workspace, which contains the projects of a team
+#[derive(Debug, Deserialize, Clone)]
+pub struct Workspace {
+    /// The workspace ID
+    pub id: String,
+    /// The workspace name
+    pub name: String,
+    /// The workspace description ([None] if the workspace has no description)
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The [plan](WorkspacePlan) of the workspace
+    pub plan: WorkspacePlan,
+    /// The [role](WorkspaceRole) of the current user in the workspace
+    pub role: WorkspaceRole,
+    /// The ID of the user who created the workspace
+    #[serde(default)]
+    pub creator_id: Option<String>,
+    /// When the workspace was created (ex: "2024-05-03T09:30:04.000000Z")
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl Workspace {
+    /// Get all [projects](Project) of this workspace that the current user has joined
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::workspaces::get_workspace_projects(config, workspace.id)`](crate::workspaces::get_workspace_projects)
+    pub async fn get_projects(
+        self,
+        config: &TodoistConfig,
+    ) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::workspaces::get_workspace_projects(config, self.id).await
+    }
+
+    /// Get all [members](WorkspaceMember) of this workspace
+    ///
+    /// This method is a shortcut for [`todoist_rest_api::workspaces::get_workspace_members(config, workspace.id)`](crate::workspaces::get_workspace_members)
+    pub async fn get_members(
+        self,
+        config: &TodoistConfig,
+    ) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+        crate::workspaces::get_workspace_members(config, self.id).await
+    }
+}
+
+/// A member of a [Workspace]
+#[derive(Debug, Deserialize, Clone)]
+pub struct WorkspaceMember {
+    /// The user ID of the member
+    pub user_id: String,
+    /// The ID of the [Workspace]
+    pub workspace_id: String,
+    /// The member's name (ex: "Bob")
+    #[serde(rename = "full_name")]
+    pub name: String,
+    /// The member's email address (ex: "bob@example.com")
+    #[serde(rename = "user_email")]
+    pub email: String,
+    /// The [role](WorkspaceRole) of the member in the workspace
+    pub role: WorkspaceRole,
+    /// The ID of the member's avatar ([None] if the member has no avatar)
+    #[serde(default)]
+    pub image_id: Option<String>,
+    /// The fields returned by the Todoist API that are not part of this structure
+    /// (ex: fields added to the API after this version)
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The plan of a [Workspace]
+///
+/// Plans added to Todoist after this version are deserialized as [WorkspacePlan::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum WorkspacePlan {
+    /// The free plan
+    Starter,
+    /// The paid plan
+    Business,
+    /// A plan that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The role of a user in a [Workspace]
+///
+/// Roles added to Todoist after this version are deserialized as [WorkspaceRole::Unknown].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum WorkspaceRole {
+    /// Can manage the workspace and its members
+    Admin,
+    /// Can join and create the projects of the workspace
+    Member,
+    /// Can only access the projects they were invited to
+    Guest,
+    /// A role that is not known by this version, with its name in the Todoist API
+    #[serde(untagged)]
+    Unknown(String),
+}
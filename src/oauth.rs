@@ -0,0 +1,316 @@
+//! Todoist OAuth 2 authorization code flow (<https://developer.todoist.com/guides/#oauth>)
+//!
+//! 1. Create an [OAuthClient] with the client ID and secret from the App Management Console
+//! 2. Redirect the user to the URL of an [AuthorizationRequest] and store the request
+//! 3. Once Todoist redirects back, pass the returned `code` and `state` to
+//!    [OAuthClient::complete_authorization]
+//! 4. Create a [TodoistConfig] for the user with [AccessToken::into_config]
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::internal::request::{check_response_status, paths, TodoistAPIError};
+use crate::secret::SecretString;
+use crate::todoist_config::{ApiVersion, TodoistConfig, TodoistConfigCreationErrors};
+
+/// The URL that users are redirected to in order to authorize an app
+pub const AUTHORIZE_URL: &str = "https://todoist.com/oauth/authorize";
+/// The URL used to exchange an authorization code for an access token
+pub const ACCESS_TOKEN_URL: &str = "https://todoist.com/oauth/access_token";
+
+/// The permissions that an app can request (<https://developer.todoist.com/guides/#authorization-request>)
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Grants permission to add new tasks (the application cannot read or modify any existing data)
+    #[serde(rename = "task:add")]
+    TaskAdd,
+    /// Grants read-only access to application data, including tasks, projects, labels, and filters
+    #[serde(rename = "data:read")]
+    DataRead,
+    /// Grants read and write access to application data, including tasks, projects, labels, and filters
+    #[serde(rename = "data:read_write")]
+    DataReadWrite,
+    /// Grants permission to delete application data, including tasks, labels, and filters
+    #[serde(rename = "data:delete")]
+    DataDelete,
+    /// Grants permission to delete projects
+    #[serde(rename = "project:delete")]
+    ProjectDelete,
+    /// Grants permission to list backups, bypassing MFA requirements
+    #[serde(rename = "backups:read")]
+    BackupsRead,
+}
+
+impl Scope {
+    /// Get the name of the [Scope] used by the Todoist API (ex: `data:read`)
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Scope::TaskAdd => "task:add",
+            Scope::DataRead => "data:read",
+            Scope::DataReadWrite => "data:read_write",
+            Scope::DataDelete => "data:delete",
+            Scope::ProjectDelete => "project:delete",
+            Scope::BackupsRead => "backups:read",
+        }
+    }
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// A Todoist app that users can authorize
+#[derive(Debug, Clone)]
+pub struct OAuthClient {
+    client_id: String,
+    client_secret: SecretString,
+    client: Client,
+}
+
+impl OAuthClient {
+    /// Creates a new [OAuthClient]
+    ///
+    /// # Arguments
+    /// * `client_id` - The unique Client ID of the Todoist app
+    /// * `client_secret` - The unique Client Secret of the Todoist app
+    pub fn new(
+        client_id: String,
+        client_secret: impl Into<SecretString>,
+    ) -> Result<OAuthClient, TodoistConfigCreationErrors> {
+        Ok(OAuthClient {
+            client_id,
+            client_secret: client_secret.into(),
+            client: Client::builder().build()?,
+        })
+    }
+
+    /// Creates a new [AuthorizationRequest] with a random `state` to protect against CSRF attacks
+    ///
+    /// The request should be stored (ex: in the user's session) until Todoist redirects back.
+    pub fn create_authorization_request(&self, scopes: &[Scope]) -> AuthorizationRequest {
+        let state = Uuid::new_v4().as_simple().to_string();
+        let scope = scopes
+            .iter()
+            .map(Scope::get_name)
+            .collect::<Vec<&str>>()
+            .join(",");
+        let url = Url::parse_with_params(
+            AUTHORIZE_URL,
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("scope", &scope),
+                ("state", &state),
+            ],
+        )
+        .expect("AUTHORIZE_URL is a valid URL");
+        AuthorizationRequest {
+            url: url.to_string(),
+            state,
+            scopes: scopes.to_vec(),
+        }
+    }
+
+    /// Validates the `state` returned by Todoist and exchanges the `code` for an [AccessToken]
+    pub async fn complete_authorization(
+        &self,
+        request: &AuthorizationRequest,
+        code: &str,
+        state: &str,
+    ) -> Result<AccessToken, OAuthError> {
+        request.validate_state(state)?;
+        self.exchange_code(code).await
+    }
+
+    /// Exchanges an authorization `code` for an [AccessToken]
+    ///
+    /// Prefer [OAuthClient::complete_authorization], which also validates the `state` parameter.
+    pub async fn exchange_code(&self, code: &str) -> Result<AccessToken, OAuthError> {
+        let response = self
+            .client
+            .post(ACCESS_TOKEN_URL)
+            .form(&[
+                ("client_id", self.client_id.as_str()),
+                ("client_secret", self.client_secret.expose_secret()),
+                ("code", code),
+            ])
+            .send()
+            .await
+            .map_err(TodoistAPIError::from)?;
+        check_response_status(&response)?;
+        Ok(response.json().await.map_err(TodoistAPIError::from)?)
+    }
+
+    /// Revokes an access token, so that it can no longer be used
+    pub async fn revoke_token(&self, access_token: &SecretString) -> Result<(), OAuthError> {
+        let response = self
+            .client
+            .delete(self.get_revoke_token_url(access_token))
+            .send()
+            .await
+            .map_err(TodoistAPIError::from)?;
+        check_response_status(&response)?;
+        Ok(())
+    }
+
+    /// Get the API v1 URL that revokes `access_token`, with the credentials of the app as parameters
+    fn get_revoke_token_url(&self, access_token: &SecretString) -> Url {
+        let mut url = Url::parse(&format!(
+            "{}{}",
+            ApiVersion::V1.get_base_url(),
+            paths::ACCESS_TOKENS
+        ))
+        .expect("the access tokens URL is valid");
+        url.query_pairs_mut()
+            .append_pair("client_id", &self.client_id)
+            .append_pair("client_secret", self.client_secret.expose_secret())
+            .append_pair("access_token", access_token.expose_secret());
+        url
+    }
+}
+
+/// A pending authorization created by [OAuthClient::create_authorization_request]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AuthorizationRequest {
+    /// The URL that the user should be redirected to
+    pub url: String,
+    /// The random secret string that Todoist will send back with the authorization code
+    pub state: String,
+    /// The scopes that were requested
+    pub scopes: Vec<Scope>,
+}
+
+impl AuthorizationRequest {
+    /// Checks that the `state` returned by Todoist matches the state of this request
+    pub fn validate_state(&self, state: &str) -> Result<(), OAuthError> {
+        let expected = self.state.as_bytes();
+        let actual = state.as_bytes();
+        // Compare in constant time to avoid leaking the expected state
+        let difference = expected
+            .iter()
+            .zip(actual)
+            .fold(expected.len() ^ actual.len(), |acc, (a, b)| {
+                acc | (a ^ b) as usize
+            });
+        if difference != 0 {
+            return Err(OAuthError::StateMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// An access token returned by Todoist once a user has authorized an app
+#[derive(Debug, Deserialize, Clone)]
+pub struct AccessToken {
+    /// The token used to access the Todoist API on behalf of the user
+    pub access_token: SecretString,
+    /// The type of the token (always `Bearer`)
+    pub token_type: String,
+}
+
+impl AccessToken {
+    /// Creates a [TodoistConfig] that acts on behalf of the user that authorized the app
+    pub fn into_config(self) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+        TodoistConfig::new(self.access_token)
+    }
+}
+
+impl TryFrom<AccessToken> for TodoistConfig {
+    type Error = TodoistConfigCreationErrors;
+
+    fn try_from(value: AccessToken) -> Result<Self, Self::Error> {
+        value.into_config()
+    }
+}
+
+/// Errors that can occur during the OAuth authorization flow
+#[derive(Debug)]
+pub enum OAuthError {
+    /// The `state` returned by Todoist did not match the [AuthorizationRequest],
+    /// which may indicate a CSRF attack
+    StateMismatch,
+    /// The request to Todoist failed
+    APIError(TodoistAPIError),
+}
+
+impl Display for OAuthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OAuthError::StateMismatch => write!(f, "OAuth state mismatch"),
+            OAuthError::APIError(e) => write!(f, "OAuth request failed - {}", e),
+        }
+    }
+}
+
+impl Error for OAuthError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            OAuthError::StateMismatch => None,
+            OAuthError::APIError(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<TodoistAPIError> for OAuthError {
+    fn from(value: TodoistAPIError) -> Self {
+        OAuthError::APIError(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_client() -> OAuthClient {
+        OAuthClient::new("0123456789abcdef".into(), "secret").unwrap()
+    }
+
+    #[test]
+    fn authorization_url() {
+        let request =
+            create_client().create_authorization_request(&[Scope::DataRead, Scope::TaskAdd]);
+        assert_eq!(request.state.len(), 32);
+        assert_eq!(
+            request.url,
+            format!(
+                "{}?client_id=0123456789abcdef&scope=data%3Aread%2Ctask%3Aadd&state={}",
+                AUTHORIZE_URL, request.state
+            )
+        );
+    }
+
+    #[test]
+    fn revoke_token_url() {
+        let url = create_client().get_revoke_token_url(&SecretString::from("a token"));
+        assert_eq!(
+            url.as_str(),
+            "https://api.todoist.com/api/v1/access_tokens\
+             ?client_id=0123456789abcdef&client_secret=secret&access_token=a+token"
+        );
+    }
+
+    #[test]
+    fn authorization_state_is_random() {
+        let client = create_client();
+        assert_ne!(
+            client.create_authorization_request(&[]).state,
+            client.create_authorization_request(&[]).state
+        );
+    }
+
+    #[test]
+    fn validate_state() {
+        let request = create_client().create_authorization_request(&[Scope::DataReadWrite]);
+        assert!(request.validate_state(&request.state.clone()).is_ok());
+        assert!(request.validate_state("").is_err());
+        assert!(request.validate_state(&request.state[1..]).is_err());
+        assert!(request
+            .validate_state(&request.state.replace(|_| true, "0"))
+            .is_err());
+    }
+}
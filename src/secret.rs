@@ -0,0 +1,72 @@
+//! A string type for secrets such as API tokens
+
+use std::fmt::{Debug, Display, Formatter};
+
+use serde::Deserialize;
+use zeroize::Zeroize;
+
+/// A secret string (such as an API token) that is never printed
+/// and is zeroed in memory once dropped
+///
+/// Both the [Debug] and [Display] implementations print `[REDACTED]`.
+/// Use [SecretString::expose_secret] to access the actual value.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    /// Creates a new [SecretString]
+    pub fn new(secret: String) -> SecretString {
+        SecretString(secret)
+    }
+
+    /// Get the actual value of the secret
+    ///
+    /// Take care to not log or otherwise persist the returned value.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for SecretString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretString([REDACTED])")
+    }
+}
+
+impl Display for SecretString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        SecretString::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        SecretString::new(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_is_redacted() {
+        let secret = SecretString::from("0123456789abcdef");
+        assert_eq!(format!("{}", secret), "[REDACTED]");
+        assert_eq!(format!("{:?}", secret), "SecretString([REDACTED])");
+        assert_eq!(secret.expose_secret(), "0123456789abcdef");
+    }
+}
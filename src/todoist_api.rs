@@ -0,0 +1,1023 @@
+//! A trait covering every operation of the Todoist API, to allow for dependency injection
+//!
+//! Code that depends on [TodoistApi] instead of calling the functions in [crate::api](crate) directly
+//! can be tested with a fake implementation (such as `FakeTodoistApi` from the `fake` feature).
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt;
+use tokio::io::AsyncWrite;
+
+use crate::attachments::DownloadedFile;
+use crate::bulk::{NewTask, TaskUpdate};
+use crate::client::TodoistClient;
+use crate::comments::TaskOrProjectID;
+use crate::internal::request::TodoistAPIError;
+use crate::label_tools::{LabelMergeReport, LabelRenameReport, OrphanLabels};
+use crate::model::collaborator::{Collaborator, CollaboratorRole};
+use crate::model::color::Color;
+use crate::model::comment::{Comment, CommentAttachment};
+use crate::model::label::PersonalLabel;
+use crate::model::project::{Project, ProjectViewStyle};
+use crate::model::reminder::{Reminder, ReminderType};
+use crate::model::section::Section;
+use crate::model::task::{Task, TaskDuration};
+use crate::model::workspace::{Workspace, WorkspaceMember};
+use crate::reorder::{OrderChange, ReorderTarget};
+use crate::uploads::UploadSource;
+
+/// Every operation of the Todoist API
+///
+/// This trait is implemented by [TodoistClient], which calls the matching functions in [crate::api](crate).
+#[allow(clippy::too_many_arguments)]
+#[async_trait]
+pub trait TodoistApi: Send + Sync {
+    /// See [crate::tasks::get_active_tasks]
+    async fn get_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> Result<Vec<Task>, TodoistAPIError>;
+
+    /// See [crate::tasks::stream_active_tasks]
+    fn stream_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> BoxStream<'_, Result<Task, TodoistAPIError>>;
+
+    /// See [crate::tasks::create_new_task]
+    async fn create_new_task(
+        &self,
+        content: String,
+        description: Option<String>,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        parent_id: Option<String>,
+        order: Option<u32>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError>;
+
+    /// See [crate::tasks::get_active_task]
+    async fn get_active_task(&self, task_id: String) -> Result<Task, TodoistAPIError>;
+
+    /// See [crate::tasks::update_task]
+    async fn update_task(
+        &self,
+        task_id: String,
+        content: Option<String>,
+        description: Option<String>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError>;
+
+    /// See [crate::tasks::close_task]
+    async fn close_task(&self, task_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::tasks::reopen_task]
+    async fn reopen_task(&self, task_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::tasks::delete_task]
+    async fn delete_task(&self, task_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::get_all_projects]
+    async fn get_all_projects(&self) -> Result<Vec<Project>, TodoistAPIError>;
+
+    /// See [crate::projects::stream_all_projects]
+    fn stream_all_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>>;
+
+    /// See [crate::projects::create_new_project]
+    async fn create_new_project(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError>;
+
+    /// See [crate::projects::get_project]
+    async fn get_project(&self, project_id: String) -> Result<Project, TodoistAPIError>;
+
+    /// See [crate::projects::update_project]
+    async fn update_project(
+        &self,
+        project_id: String,
+        name: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError>;
+
+    /// See [crate::projects::delete_project]
+    async fn delete_project(&self, project_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::get_all_collaborators]
+    async fn get_all_collaborators(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError>;
+
+    /// See [crate::projects::get_collaborators_with_roles]
+    async fn get_collaborators_with_roles(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError>;
+
+    /// See [crate::projects::share_project]
+    async fn share_project(
+        &self,
+        project_id: String,
+        email: String,
+        role: Option<CollaboratorRole>,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::delete_collaborator]
+    async fn delete_collaborator(
+        &self,
+        project_id: String,
+        email: String,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::accept_invitation]
+    async fn accept_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::reject_invitation]
+    async fn reject_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::projects::archive_project]
+    async fn archive_project(&self, project_id: String) -> Result<Project, TodoistAPIError>;
+
+    /// See [crate::projects::unarchive_project]
+    async fn unarchive_project(&self, project_id: String) -> Result<Project, TodoistAPIError>;
+
+    /// See [crate::projects::get_archived_projects]
+    async fn get_archived_projects(&self) -> Result<Vec<Project>, TodoistAPIError>;
+
+    /// See [crate::projects::stream_archived_projects]
+    fn stream_archived_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>>;
+
+    /// See [crate::sections::get_all_sections]
+    async fn get_all_sections(
+        &self,
+        project_id: Option<String>,
+    ) -> Result<Vec<Section>, TodoistAPIError>;
+
+    /// See [crate::sections::create_new_section]
+    async fn create_new_section(
+        &self,
+        project_id: String,
+        name: String,
+        order: Option<u32>,
+    ) -> Result<Section, TodoistAPIError>;
+
+    /// See [crate::sections::get_section]
+    async fn get_section(&self, section_id: String) -> Result<Section, TodoistAPIError>;
+
+    /// See [crate::sections::update_section]
+    async fn update_section(
+        &self,
+        section_id: String,
+        name: String,
+    ) -> Result<Section, TodoistAPIError>;
+
+    /// See [crate::sections::delete_section]
+    async fn delete_section(&self, section_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::sections::archive_section]
+    async fn archive_section(&self, section_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::sections::unarchive_section]
+    async fn unarchive_section(&self, section_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::sections::move_section]
+    async fn move_section(
+        &self,
+        section_id: String,
+        project_id: String,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::sections::reorder_sections]
+    async fn reorder_sections(&self, section_ids: Vec<String>) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::labels::get_all_personal_labels]
+    async fn get_all_personal_labels(&self) -> Result<Vec<PersonalLabel>, TodoistAPIError>;
+
+    /// See [crate::labels::create_new_personal_label]
+    async fn create_new_personal_label(
+        &self,
+        name: String,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError>;
+
+    /// See [crate::labels::get_personal_label]
+    async fn get_personal_label(&self, label_id: String) -> Result<PersonalLabel, TodoistAPIError>;
+
+    /// See [crate::labels::update_personal_label]
+    async fn update_personal_label(
+        &self,
+        label_id: String,
+        name: Option<String>,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError>;
+
+    /// See [crate::labels::delete_personal_label]
+    async fn delete_personal_label(&self, label_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::labels::get_all_shared_labels]
+    async fn get_all_shared_labels(
+        &self,
+        omit_personal: Option<bool>,
+    ) -> Result<Vec<String>, TodoistAPIError>;
+
+    /// See [crate::labels::rename_shared_labels]
+    async fn rename_shared_labels(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::labels::remove_shared_labels]
+    async fn remove_shared_labels(&self, name: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::comments::get_all_comments]
+    async fn get_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> Result<Vec<Comment>, TodoistAPIError>;
+
+    /// See [crate::comments::stream_all_comments]
+    fn stream_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> BoxStream<'_, Result<Comment, TodoistAPIError>>;
+
+    /// See [crate::comments::create_new_comment]
+    async fn create_new_comment(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+        content: String,
+        attachment: Option<CommentAttachment>,
+        uids_to_notify: Option<Vec<String>>,
+    ) -> Result<Comment, TodoistAPIError>;
+
+    /// See [crate::comments::get_comment]
+    async fn get_comment(&self, comment_id: String) -> Result<Comment, TodoistAPIError>;
+
+    /// See [crate::comments::update_comment]
+    async fn update_comment(
+        &self,
+        comment_id: String,
+        content: String,
+    ) -> Result<Comment, TodoistAPIError>;
+
+    /// See [crate::comments::delete_comment]
+    async fn delete_comment(&self, comment_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::reminders::get_all_reminders]
+    async fn get_all_reminders(&self) -> Result<Vec<Reminder>, TodoistAPIError>;
+
+    /// See [crate::reminders::get_task_reminders]
+    async fn get_task_reminders(&self, task_id: String) -> Result<Vec<Reminder>, TodoistAPIError>;
+
+    /// See [crate::reminders::get_reminder]
+    async fn get_reminder(&self, reminder_id: String) -> Result<Reminder, TodoistAPIError>;
+
+    /// See [crate::reminders::create_new_reminder]
+    async fn create_new_reminder(
+        &self,
+        task_id: String,
+        reminder_type: ReminderType,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError>;
+
+    /// See [crate::reminders::update_reminder]
+    async fn update_reminder(
+        &self,
+        reminder_id: String,
+        reminder_type: Option<ReminderType>,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError>;
+
+    /// See [crate::reminders::delete_reminder]
+    async fn delete_reminder(&self, reminder_id: String) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::uploads::upload_file]
+    async fn upload_file(&self, source: UploadSource)
+        -> Result<CommentAttachment, TodoistAPIError>;
+
+    /// See [crate::attachments::download_attachment]
+    async fn download_attachment(
+        &self,
+        attachment: &CommentAttachment,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<DownloadedFile, TodoistAPIError>;
+
+    /// See [crate::attachments::download_attachment_to_path]
+    async fn download_attachment_to_path(
+        &self,
+        attachment: &CommentAttachment,
+        path: &Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<DownloadedFile, TodoistAPIError>;
+
+    /// See [crate::attachments::download_attachment_cached]
+    async fn download_attachment_cached(
+        &self,
+        attachment: &CommentAttachment,
+        cache_directory: &Path,
+    ) -> Result<PathBuf, TodoistAPIError>;
+
+    /// See [crate::workspaces::get_all_workspaces]
+    async fn get_all_workspaces(&self) -> Result<Vec<Workspace>, TodoistAPIError>;
+
+    /// See [crate::workspaces::get_workspace_members]
+    async fn get_workspace_members(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<WorkspaceMember>, TodoistAPIError>;
+
+    /// See [crate::workspaces::get_workspace_projects]
+    async fn get_workspace_projects(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<Project>, TodoistAPIError>;
+
+    /// See [crate::reorder::reorder]
+    async fn reorder(&self, target: ReorderTarget, ids: Vec<String>)
+        -> Result<(), TodoistAPIError>;
+
+    /// See [crate::reorder::apply_order_changes]
+    async fn apply_order_changes(
+        &self,
+        target: ReorderTarget,
+        changes: Vec<OrderChange>,
+    ) -> Result<(), TodoistAPIError>;
+
+    /// See [crate::bulk::close_tasks]
+    async fn close_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>>;
+
+    /// See [crate::bulk::delete_tasks]
+    async fn delete_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>>;
+
+    /// See [crate::bulk::create_tasks]
+    async fn create_tasks(
+        &self,
+        tasks: Vec<NewTask>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>>;
+
+    /// See [crate::bulk::update_tasks]
+    async fn update_tasks(
+        &self,
+        updates: Vec<TaskUpdate>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>>;
+
+    /// See [crate::bulk::add_label_to_tasks]
+    async fn add_label_to_tasks(
+        &self,
+        tasks: Vec<Task>,
+        label: String,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>>;
+
+    /// See [crate::label_tools::merge_labels]
+    async fn merge_labels(
+        &self,
+        from: String,
+        into: String,
+        concurrency: usize,
+    ) -> Result<LabelMergeReport, TodoistAPIError>;
+
+    /// See [crate::label_tools::rename_label_everywhere]
+    async fn rename_label_everywhere(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<LabelRenameReport, TodoistAPIError>;
+
+    /// See [crate::label_tools::find_orphan_labels]
+    async fn find_orphan_labels(&self) -> Result<OrphanLabels, TodoistAPIError>;
+}
+
+#[allow(clippy::too_many_arguments)]
+#[async_trait]
+impl TodoistApi for TodoistClient {
+    async fn get_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> Result<Vec<Task>, TodoistAPIError> {
+        crate::tasks::get_active_tasks(
+            self.config(),
+            project_id,
+            section_id,
+            label,
+            filter,
+            lang,
+            ids,
+        )
+        .await
+    }
+
+    fn stream_active_tasks(
+        &self,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        label: Option<String>,
+        filter: Option<String>,
+        lang: Option<String>,
+        ids: Option<&[String]>,
+    ) -> BoxStream<'_, Result<Task, TodoistAPIError>> {
+        crate::tasks::stream_active_tasks(
+            self.config(),
+            project_id,
+            section_id,
+            label,
+            filter,
+            lang,
+            ids,
+        )
+        .boxed()
+    }
+
+    async fn create_new_task(
+        &self,
+        content: String,
+        description: Option<String>,
+        project_id: Option<String>,
+        section_id: Option<String>,
+        parent_id: Option<String>,
+        order: Option<u32>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        crate::tasks::create_new_task(
+            self.config(),
+            content,
+            description,
+            project_id,
+            section_id,
+            parent_id,
+            order,
+            labels,
+            priority,
+            due_string,
+            due_date,
+            due_datetime,
+            due_lang,
+            assignee_id,
+            duration,
+        )
+        .await
+    }
+
+    async fn get_active_task(&self, task_id: String) -> Result<Task, TodoistAPIError> {
+        crate::tasks::get_active_task(self.config(), task_id).await
+    }
+
+    async fn update_task(
+        &self,
+        task_id: String,
+        content: Option<String>,
+        description: Option<String>,
+        labels: Option<Vec<String>>,
+        priority: Option<u8>,
+        due_string: Option<String>,
+        due_date: Option<String>,
+        due_datetime: Option<String>,
+        due_lang: Option<String>,
+        assignee_id: Option<String>,
+        duration: Option<TaskDuration>,
+    ) -> Result<Task, TodoistAPIError> {
+        crate::tasks::update_task(
+            self.config(),
+            task_id,
+            content,
+            description,
+            labels,
+            priority,
+            due_string,
+            due_date,
+            due_datetime,
+            due_lang,
+            assignee_id,
+            duration,
+        )
+        .await
+    }
+
+    async fn close_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::close_task(self.config(), task_id).await
+    }
+
+    async fn reopen_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::reopen_task(self.config(), task_id).await
+    }
+
+    async fn delete_task(&self, task_id: String) -> Result<(), TodoistAPIError> {
+        crate::tasks::delete_task(self.config(), task_id).await
+    }
+
+    async fn get_all_projects(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::projects::get_all_projects(self.config()).await
+    }
+
+    fn stream_all_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>> {
+        crate::projects::stream_all_projects(self.config()).boxed()
+    }
+
+    async fn create_new_project(
+        &self,
+        name: String,
+        parent_id: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        crate::projects::create_new_project(
+            self.config(),
+            name,
+            parent_id,
+            color,
+            is_favorite,
+            view_style,
+        )
+        .await
+    }
+
+    async fn get_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::get_project(self.config(), project_id).await
+    }
+
+    async fn update_project(
+        &self,
+        project_id: String,
+        name: Option<String>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+        view_style: Option<ProjectViewStyle>,
+    ) -> Result<Project, TodoistAPIError> {
+        crate::projects::update_project(
+            self.config(),
+            project_id,
+            name,
+            color,
+            is_favorite,
+            view_style,
+        )
+        .await
+    }
+
+    async fn delete_project(&self, project_id: String) -> Result<(), TodoistAPIError> {
+        crate::projects::delete_project(self.config(), project_id).await
+    }
+
+    async fn get_all_collaborators(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        crate::projects::get_all_collaborators(self.config(), project_id).await
+    }
+
+    async fn get_collaborators_with_roles(
+        &self,
+        project_id: String,
+    ) -> Result<Vec<Collaborator>, TodoistAPIError> {
+        crate::projects::get_collaborators_with_roles(self.config(), project_id).await
+    }
+
+    async fn share_project(
+        &self,
+        project_id: String,
+        email: String,
+        role: Option<CollaboratorRole>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::share_project(self.config(), project_id, email, role).await
+    }
+
+    async fn delete_collaborator(
+        &self,
+        project_id: String,
+        email: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::delete_collaborator(self.config(), project_id, email).await
+    }
+
+    async fn accept_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::accept_invitation(self.config(), invitation_id, invitation_secret).await
+    }
+
+    async fn reject_invitation(
+        &self,
+        invitation_id: String,
+        invitation_secret: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::projects::reject_invitation(self.config(), invitation_id, invitation_secret).await
+    }
+
+    async fn archive_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::archive_project(self.config(), project_id).await
+    }
+
+    async fn unarchive_project(&self, project_id: String) -> Result<Project, TodoistAPIError> {
+        crate::projects::unarchive_project(self.config(), project_id).await
+    }
+
+    async fn get_archived_projects(&self) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::projects::get_archived_projects(self.config()).await
+    }
+
+    fn stream_archived_projects(&self) -> BoxStream<'_, Result<Project, TodoistAPIError>> {
+        crate::projects::stream_archived_projects(self.config()).boxed()
+    }
+
+    async fn get_all_sections(
+        &self,
+        project_id: Option<String>,
+    ) -> Result<Vec<Section>, TodoistAPIError> {
+        crate::sections::get_all_sections(self.config(), project_id).await
+    }
+
+    async fn create_new_section(
+        &self,
+        project_id: String,
+        name: String,
+        order: Option<u32>,
+    ) -> Result<Section, TodoistAPIError> {
+        crate::sections::create_new_section(self.config(), project_id, name, order).await
+    }
+
+    async fn get_section(&self, section_id: String) -> Result<Section, TodoistAPIError> {
+        crate::sections::get_section(self.config(), section_id).await
+    }
+
+    async fn update_section(
+        &self,
+        section_id: String,
+        name: String,
+    ) -> Result<Section, TodoistAPIError> {
+        crate::sections::update_section(self.config(), section_id, name).await
+    }
+
+    async fn delete_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::delete_section(self.config(), section_id).await
+    }
+
+    async fn archive_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::archive_section(self.config(), section_id).await
+    }
+
+    async fn unarchive_section(&self, section_id: String) -> Result<(), TodoistAPIError> {
+        crate::sections::unarchive_section(self.config(), section_id).await
+    }
+
+    async fn move_section(
+        &self,
+        section_id: String,
+        project_id: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::sections::move_section(self.config(), section_id, project_id).await
+    }
+
+    async fn reorder_sections(&self, section_ids: Vec<String>) -> Result<(), TodoistAPIError> {
+        crate::sections::reorder_sections(self.config(), section_ids).await
+    }
+
+    async fn get_all_personal_labels(&self) -> Result<Vec<PersonalLabel>, TodoistAPIError> {
+        crate::labels::get_all_personal_labels(self.config()).await
+    }
+
+    async fn create_new_personal_label(
+        &self,
+        name: String,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::create_new_personal_label(self.config(), name, order, color, is_favorite)
+            .await
+    }
+
+    async fn get_personal_label(&self, label_id: String) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::get_personal_label(self.config(), label_id).await
+    }
+
+    async fn update_personal_label(
+        &self,
+        label_id: String,
+        name: Option<String>,
+        order: Option<u32>,
+        color: Option<Color>,
+        is_favorite: Option<bool>,
+    ) -> Result<PersonalLabel, TodoistAPIError> {
+        crate::labels::update_personal_label(
+            self.config(),
+            label_id,
+            name,
+            order,
+            color,
+            is_favorite,
+        )
+        .await
+    }
+
+    async fn delete_personal_label(&self, label_id: String) -> Result<(), TodoistAPIError> {
+        crate::labels::delete_personal_label(self.config(), label_id).await
+    }
+
+    async fn get_all_shared_labels(
+        &self,
+        omit_personal: Option<bool>,
+    ) -> Result<Vec<String>, TodoistAPIError> {
+        crate::labels::get_all_shared_labels(self.config(), omit_personal).await
+    }
+
+    async fn rename_shared_labels(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<(), TodoistAPIError> {
+        crate::labels::rename_shared_labels(self.config(), name, new_name).await
+    }
+
+    async fn remove_shared_labels(&self, name: String) -> Result<(), TodoistAPIError> {
+        crate::labels::remove_shared_labels(self.config(), name).await
+    }
+
+    async fn get_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> Result<Vec<Comment>, TodoistAPIError> {
+        crate::comments::get_all_comments(self.config(), task_or_project_id).await
+    }
+
+    fn stream_all_comments(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+    ) -> BoxStream<'_, Result<Comment, TodoistAPIError>> {
+        crate::comments::stream_all_comments(self.config(), task_or_project_id).boxed()
+    }
+
+    async fn create_new_comment(
+        &self,
+        task_or_project_id: &TaskOrProjectID,
+        content: String,
+        attachment: Option<CommentAttachment>,
+        uids_to_notify: Option<Vec<String>>,
+    ) -> Result<Comment, TodoistAPIError> {
+        crate::comments::create_new_comment(
+            self.config(),
+            task_or_project_id,
+            content,
+            attachment,
+            uids_to_notify,
+        )
+        .await
+    }
+
+    async fn get_comment(&self, comment_id: String) -> Result<Comment, TodoistAPIError> {
+        crate::comments::get_comment(self.config(), comment_id).await
+    }
+
+    async fn update_comment(
+        &self,
+        comment_id: String,
+        content: String,
+    ) -> Result<Comment, TodoistAPIError> {
+        crate::comments::update_comment(self.config(), comment_id, content).await
+    }
+
+    async fn delete_comment(&self, comment_id: String) -> Result<(), TodoistAPIError> {
+        crate::comments::delete_comment(self.config(), comment_id).await
+    }
+
+    async fn get_all_reminders(&self) -> Result<Vec<Reminder>, TodoistAPIError> {
+        crate::reminders::get_all_reminders(self.config()).await
+    }
+
+    async fn get_task_reminders(&self, task_id: String) -> Result<Vec<Reminder>, TodoistAPIError> {
+        crate::reminders::get_task_reminders(self.config(), task_id).await
+    }
+
+    async fn get_reminder(&self, reminder_id: String) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::get_reminder(self.config(), reminder_id).await
+    }
+
+    async fn create_new_reminder(
+        &self,
+        task_id: String,
+        reminder_type: ReminderType,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::create_new_reminder(self.config(), task_id, reminder_type, notify_uid)
+            .await
+    }
+
+    async fn update_reminder(
+        &self,
+        reminder_id: String,
+        reminder_type: Option<ReminderType>,
+        notify_uid: Option<String>,
+    ) -> Result<Reminder, TodoistAPIError> {
+        crate::reminders::update_reminder(self.config(), reminder_id, reminder_type, notify_uid)
+            .await
+    }
+
+    async fn delete_reminder(&self, reminder_id: String) -> Result<(), TodoistAPIError> {
+        crate::reminders::delete_reminder(self.config(), reminder_id).await
+    }
+
+    async fn upload_file(
+        &self,
+        source: UploadSource,
+    ) -> Result<CommentAttachment, TodoistAPIError> {
+        crate::uploads::upload_file(self.config(), source).await
+    }
+
+    async fn download_attachment(
+        &self,
+        attachment: &CommentAttachment,
+        writer: &mut (dyn AsyncWrite + Send + Unpin),
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        crate::attachments::download_attachment(self.config(), attachment, writer).await
+    }
+
+    async fn download_attachment_to_path(
+        &self,
+        attachment: &CommentAttachment,
+        path: &Path,
+        expected_sha256: Option<&str>,
+    ) -> Result<DownloadedFile, TodoistAPIError> {
+        crate::attachments::download_attachment_to_path(
+            self.config(),
+            attachment,
+            path,
+            expected_sha256,
+        )
+        .await
+    }
+
+    async fn download_attachment_cached(
+        &self,
+        attachment: &CommentAttachment,
+        cache_directory: &Path,
+    ) -> Result<PathBuf, TodoistAPIError> {
+        crate::attachments::download_attachment_cached(self.config(), attachment, cache_directory)
+            .await
+    }
+
+    async fn get_all_workspaces(&self) -> Result<Vec<Workspace>, TodoistAPIError> {
+        crate::workspaces::get_all_workspaces(self.config()).await
+    }
+
+    async fn get_workspace_members(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<WorkspaceMember>, TodoistAPIError> {
+        crate::workspaces::get_workspace_members(self.config(), workspace_id).await
+    }
+
+    async fn get_workspace_projects(
+        &self,
+        workspace_id: String,
+    ) -> Result<Vec<Project>, TodoistAPIError> {
+        crate::workspaces::get_workspace_projects(self.config(), workspace_id).await
+    }
+
+    async fn reorder(
+        &self,
+        target: ReorderTarget,
+        ids: Vec<String>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::reorder::reorder(self.config(), target, ids).await
+    }
+
+    async fn apply_order_changes(
+        &self,
+        target: ReorderTarget,
+        changes: Vec<OrderChange>,
+    ) -> Result<(), TodoistAPIError> {
+        crate::reorder::apply_order_changes(self.config(), target, changes).await
+    }
+
+    async fn close_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        crate::bulk::close_tasks(self.config(), task_ids, concurrency).await
+    }
+
+    async fn delete_tasks(
+        &self,
+        task_ids: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<(), TodoistAPIError>> {
+        crate::bulk::delete_tasks(self.config(), task_ids, concurrency).await
+    }
+
+    async fn create_tasks(
+        &self,
+        tasks: Vec<NewTask>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::create_tasks(self.config(), tasks, concurrency).await
+    }
+
+    async fn update_tasks(
+        &self,
+        updates: Vec<TaskUpdate>,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::update_tasks(self.config(), updates, concurrency).await
+    }
+
+    async fn add_label_to_tasks(
+        &self,
+        tasks: Vec<Task>,
+        label: String,
+        concurrency: usize,
+    ) -> Vec<Result<Task, TodoistAPIError>> {
+        crate::bulk::add_label_to_tasks(self.config(), tasks, label, concurrency).await
+    }
+
+    async fn merge_labels(
+        &self,
+        from: String,
+        into: String,
+        concurrency: usize,
+    ) -> Result<LabelMergeReport, TodoistAPIError> {
+        crate::label_tools::merge_labels(self.config(), from, into, concurrency).await
+    }
+
+    async fn rename_label_everywhere(
+        &self,
+        name: String,
+        new_name: String,
+    ) -> Result<LabelRenameReport, TodoistAPIError> {
+        crate::label_tools::rename_label_everywhere(self.config(), name, new_name).await
+    }
+
+    async fn find_orphan_labels(&self) -> Result<OrphanLabels, TodoistAPIError> {
+        crate::label_tools::find_orphan_labels(self.config()).await
+    }
+}
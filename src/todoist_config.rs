@@ -1,37 +1,243 @@
 //! A structure to store the Todoist API configuration.
 
 use std::error::Error;
-use std::fmt::{Display, Formatter};
+use std::fmt::{Debug, Display, Formatter};
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
 
-use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::header::HeaderValue;
 use reqwest::Client;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::middleware::{Middleware, RateLimiter, ResponseCache, RetryPolicy};
+use crate::secret::SecretString;
+
+/// The environment variable that [TodoistConfig::from_env] reads the API token from
+pub const TOKEN_ENVIRONMENT_VARIABLE: &str = "TODOIST_API_TOKEN";
+
+/// The versions of the Todoist API that requests can be sent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApiVersion {
+    /// The REST API v2 (<https://developer.todoist.com/rest/v2/>)
+    #[default]
+    V2,
+    /// The unified API v1 (<https://developer.todoist.com/api/v1/>),
+    /// which paginates listing endpoints with cursors
+    ///
+    /// Responses are mapped into the same models as the REST API v2.
+    V1,
+}
+
+impl ApiVersion {
+    /// Get the URL that the paths of the API are relative to
+    pub fn get_base_url(&self) -> &'static str {
+        match self {
+            ApiVersion::V2 => "https://api.todoist.com/rest/v2",
+            ApiVersion::V1 => "https://api.todoist.com/api/v1",
+        }
+    }
+}
+
+type TokenRefresher =
+    Arc<dyn Fn() -> Pin<Box<dyn Future<Output = Option<SecretString>> + Send>> + Send + Sync>;
 
 /// A structure to store the Todoist API configuration.
-#[derive(Debug, Clone)]
+///
+/// The token is sent with each request instead of being part of the `reqwest` [Client],
+/// so one [Client] can be shared by the configs of many users (see [TodoistConfig::with_client]).
+///
+/// Clones of a config share the same token, so [TodoistConfig::set_token] affects all of them.
+#[derive(Clone)]
 pub struct TodoistConfig {
-    /// The Todoist API token (see <https://developer.todoist.com/rest/v2/#authorization>)
-    pub token: String,
+    token: Arc<RwLock<SecretString>>,
+    token_refresher: Option<TokenRefresher>,
+    pub(crate) api_version: ApiVersion,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) response_cache: Option<ResponseCache>,
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
     #[doc(hidden)]
     pub client: Client,
 }
 
 #[doc(hidden)]
 impl TodoistConfig {
-    pub fn new(token: String) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            "Authorization",
-            HeaderValue::from_str(&format!("Bearer {}", token)).map_err(|_| {
-                InvalidTokenFormatError {
-                    token: token.clone(),
-                }
-            })?,
-        );
+    pub fn new(
+        token: impl Into<SecretString>,
+    ) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+        TodoistConfig::with_client(Client::builder().build()?, token)
+    }
+}
+
+impl TodoistConfig {
+    /// Creates a [TodoistConfig] that sends its requests through an existing `reqwest` [Client]
+    ///
+    /// The [Client] should not have a default `Authorization` header,
+    /// as the token is added to each request.
+    ///
+    /// # Arguments
+    /// * `client` - The [Client] to use (can be shared between multiple configs)
+    /// * `token` - The Todoist API token to use (see <https://developer.todoist.com/rest/v2/#authorization>)
+    pub fn with_client(
+        client: Client,
+        token: impl Into<SecretString>,
+    ) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+        let token = token.into();
+        create_authorization_header(&token)?;
         Ok(TodoistConfig {
-            token,
-            client: Client::builder().default_headers(headers).build()?,
+            token: Arc::new(RwLock::new(token)),
+            token_refresher: None,
+            api_version: ApiVersion::default(),
+            retry_policy: None,
+            rate_limiter: None,
+            response_cache: None,
+            middleware: Vec::new(),
+            client,
         })
     }
+
+    /// Get the Todoist API token that is currently used
+    pub fn get_token(&self) -> SecretString {
+        self.token.read().unwrap().clone()
+    }
+
+    /// Replaces the Todoist API token used by this config (and all of its clones)
+    ///
+    /// The [response cache](ResponseCache) of the config is cleared,
+    /// so that responses for the previous token are never returned.
+    pub fn set_token(&self, token: impl Into<SecretString>) -> Result<(), InvalidTokenFormatError> {
+        let token = token.into();
+        create_authorization_header(&token)?;
+        *self.token.write().unwrap() = token;
+        if let Some(cache) = &self.response_cache {
+            cache.clear();
+        }
+        Ok(())
+    }
+
+    /// Sets a hook that is called when the Todoist API rejects the current token
+    /// (status code `401`)
+    ///
+    /// If the hook returns a new token, the token is [replaced](TodoistConfig::set_token)
+    /// and the request is sent again once. If it returns [None], the original error is returned.
+    pub fn with_token_refresher<F, Fut>(mut self, refresher: F) -> TodoistConfig
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<SecretString>> + Send + 'static,
+    {
+        self.token_refresher = Some(Arc::new(move || Box::pin(refresher())));
+        self
+    }
+
+    /// Sends requests to the given [ApiVersion] (defaults to [ApiVersion::V2])
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> TodoistConfig {
+        self.api_version = api_version;
+        self
+    }
+
+    /// Get the [ApiVersion] that requests are sent to
+    pub fn get_api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// Retries failed requests according to the [RetryPolicy]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> TodoistConfig {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Delays requests to stay within the limit of the [RateLimiter]
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> TodoistConfig {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Reuses recent responses of `GET` requests stored in the [ResponseCache]
+    pub fn with_response_cache(mut self, response_cache: ResponseCache) -> TodoistConfig {
+        self.response_cache = Some(response_cache);
+        self
+    }
+
+    /// Adds a [Middleware] that is called before each request is sent and after each response is received
+    ///
+    /// Middleware is called in the order it was added before a request,
+    /// and in the reverse order after a response.
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> TodoistConfig {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Calls the token refresher and stores the new token,
+    /// returning whether the token was replaced
+    pub(crate) async fn refresh_token(&self) -> bool {
+        let Some(refresher) = &self.token_refresher else {
+            return false;
+        };
+        match refresher().await {
+            Some(token) => self.set_token(token).is_ok(),
+            None => false,
+        }
+    }
+
+    pub(crate) fn get_authorization_header(&self) -> Result<HeaderValue, InvalidTokenFormatError> {
+        create_authorization_header(&self.token.read().unwrap())
+    }
+
+    /// The key of a response in the [ResponseCache], which is specific to the current token
+    /// so that a cache shared between configs never returns the responses of another account
+    pub(crate) fn get_cache_key(&self, url: &str) -> String {
+        let token_hash = Sha256::digest(self.token.read().unwrap().expose_secret().as_bytes());
+        format!("{:x} {}", token_hash, url)
+    }
+}
+
+fn create_authorization_header(
+    token: &SecretString,
+) -> Result<HeaderValue, InvalidTokenFormatError> {
+    let authorization = Zeroizing::new(format!("Bearer {}", token.expose_secret()));
+    let mut header =
+        HeaderValue::from_str(&authorization).map_err(|_| InvalidTokenFormatError {})?;
+    header.set_sensitive(true);
+    Ok(header)
+}
+
+impl Debug for TodoistConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TodoistConfig")
+            .field("token", &*self.token.read().unwrap())
+            .field("has_token_refresher", &self.token_refresher.is_some())
+            .field("api_version", &self.api_version)
+            .field("retry_policy", &self.retry_policy)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("response_cache", &self.response_cache)
+            .field("middleware_count", &self.middleware.len())
+            .field("client", &self.client)
+            .finish()
+    }
+}
+
+impl TodoistConfig {
+    /// Creates a [TodoistConfig] using the token stored in the
+    /// [`TODOIST_API_TOKEN`](TOKEN_ENVIRONMENT_VARIABLE) environment variable
+    pub fn from_env() -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+        let token = std::env::var(TOKEN_ENVIRONMENT_VARIABLE)
+            .map_err(TodoistConfigCreationErrors::MissingEnvironmentVariable)?;
+        TodoistConfig::new(token)
+    }
+
+    /// Creates a [TodoistConfig] using the token stored in a file
+    ///
+    /// Leading and trailing whitespace (such as a trailing newline) is ignored.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+        let contents = Zeroizing::new(
+            std::fs::read_to_string(path)
+                .map_err(TodoistConfigCreationErrors::TokenFileReadError)?,
+        );
+        TodoistConfig::new(contents.trim())
+    }
 }
 
 /// Creates a [TodoistConfig]
@@ -39,7 +245,9 @@ impl TodoistConfig {
 /// # Arguments
 ///
 /// * `token` - The Todoist API token to use (see <https://developer.todoist.com/rest/v2/#authorization>)
-pub fn create_config(token: String) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
+pub fn create_config(
+    token: impl Into<SecretString>,
+) -> Result<TodoistConfig, TodoistConfigCreationErrors> {
     TodoistConfig::new(token)
 }
 
@@ -53,6 +261,11 @@ pub enum TodoistConfigCreationErrors {
     /// > This method fails if a TLS backend cannot be initialized,
     /// > or the resolver cannot load the system configuration.
     HttpClientCreationError(reqwest::Error),
+    /// An error for when the [`TODOIST_API_TOKEN`](TOKEN_ENVIRONMENT_VARIABLE) environment variable
+    /// is missing or is not valid unicode
+    MissingEnvironmentVariable(std::env::VarError),
+    /// An error for when the file containing the token could not be read
+    TokenFileReadError(std::io::Error),
 }
 
 impl Display for TodoistConfigCreationErrors {
@@ -66,6 +279,8 @@ impl Error for TodoistConfigCreationErrors {
         match self {
             TodoistConfigCreationErrors::InvalidTokenFormat(ref e) => Some(e),
             TodoistConfigCreationErrors::HttpClientCreationError(ref e) => Some(e),
+            TodoistConfigCreationErrors::MissingEnvironmentVariable(ref e) => Some(e),
+            TodoistConfigCreationErrors::TokenFileReadError(ref e) => Some(e),
         }
     }
 }
@@ -83,15 +298,47 @@ impl From<reqwest::Error> for TodoistConfigCreationErrors {
 }
 
 /// An error that is thrown when the token is not in the correct format (such as containing a newline)
+///
+/// The token itself is not included, so that it cannot end up in logs.
 #[derive(Debug, Clone)]
-pub struct InvalidTokenFormatError {
-    token: String,
-}
+pub struct InvalidTokenFormatError {}
 
 impl Display for InvalidTokenFormatError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Invalid token format: '{}'", self.token)
+        write!(f, "Invalid token format")
     }
 }
 
 impl Error for InvalidTokenFormatError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_is_not_printed() {
+        let config = create_config("0123456789abcdef").unwrap();
+        assert!(!format!("{:?}", config).contains("0123456789abcdef"));
+        let error = create_config("0123456789\nabcdef").unwrap_err();
+        assert!(!format!("{:?} {}", error, error).contains("abcdef"));
+    }
+
+    #[test]
+    fn token_from_file() {
+        let path = std::env::temp_dir().join(format!("todoist-token-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "0123456789abcdef\n").unwrap();
+        let config = TodoistConfig::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(config.get_token().expose_secret(), "0123456789abcdef");
+    }
+
+    #[test]
+    fn token_is_shared_between_clones() {
+        let config = create_config("0123456789abcdef").unwrap();
+        let clone = config.clone();
+        clone.set_token("fedcba9876543210").unwrap();
+        assert_eq!(config.get_token().expose_secret(), "fedcba9876543210");
+        assert!(config.set_token("0123456789\nabcdef").is_err());
+        assert_eq!(config.get_token().expose_secret(), "fedcba9876543210");
+    }
+}
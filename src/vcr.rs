@@ -0,0 +1,473 @@
+//! Recording and replaying of Todoist API requests, for deterministic tests without a network
+//!
+//! A [Recorder] saves every request sent with a [TodoistConfig](crate::todoist_config::TodoistConfig)
+//! and the received response into a [Cassette] file.
+//! A [Replayer] answers requests with the responses of a cassette instead of sending them.
+//!
+//! Bodies are stored as text when they are valid UTF-8, and as base64 otherwise.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use todoist_rest_api::todoist_config::TodoistConfig;
+//! use todoist_rest_api::vcr::{Recorder, Replayer};
+//!
+//! // Record once with a real token...
+//! let config = TodoistConfig::from_env()?.with_middleware(Recorder::new("projects.json"));
+//! todoist_rest_api::projects::get_all_projects(&config).await?;
+//!
+//! // ...and replay in tests
+//! let config = TodoistConfig::new("token")?.with_middleware(Replayer::from_file("projects.json")?);
+//! todoist_rest_api::projects::get_all_projects(&config).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::internal::request::{APIParametersError, TodoistAPIError};
+use crate::middleware::{Middleware, TodoistRequest, TodoistResponse};
+
+/// The value that replaces sensitive headers in cassettes
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers that are never written to a cassette
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie"];
+
+/// Recorded requests and their responses
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq, Eq)]
+pub struct Cassette {
+    /// The recorded requests, in the order they were sent
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a [Cassette] from a JSON file
+    pub fn load(path: impl AsRef<Path>) -> Result<Cassette, CassetteError> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Writes the [Cassette] to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), CassetteError> {
+        Ok(std::fs::write(path, serde_json::to_vec_pretty(self)?)?)
+    }
+}
+
+/// A recorded request and its response
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    /// The request sent to the Todoist API
+    pub request: RecordedRequest,
+    /// The response received from the Todoist API
+    pub response: RecordedResponse,
+}
+
+/// A recorded request
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// The HTTP method of the request (ex: `GET`)
+    pub method: String,
+    /// The full URL of the request
+    pub url: String,
+    /// The headers of the request (sensitive headers are [redacted](REDACTED))
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The body of the request
+    #[serde(default)]
+    pub body: Option<RecordedBody>,
+}
+
+/// A recorded response
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct RecordedResponse {
+    /// The HTTP status code of the response
+    pub status: u16,
+    /// The headers of the response (sensitive headers are [redacted](REDACTED))
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    /// The body of the response
+    #[serde(default)]
+    pub body: RecordedBody,
+}
+
+/// A recorded request or response body
+///
+/// Stored as a JSON string for UTF-8 bodies, or as `{"base64": "..."}` for any other body.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum RecordedBody {
+    /// A body that is valid UTF-8
+    Text(String),
+    /// A body that is not valid UTF-8, encoded with base64
+    Binary {
+        /// The base64-encoded body
+        base64: String,
+    },
+}
+
+impl RecordedBody {
+    /// Creates a [RecordedBody] from the bytes of a body
+    pub fn from_bytes(bytes: &[u8]) -> RecordedBody {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => RecordedBody::Text(text.to_string()),
+            Err(_) => RecordedBody::Binary {
+                base64: STANDARD.encode(bytes),
+            },
+        }
+    }
+
+    /// Get the bytes of the body
+    pub fn to_bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        match self {
+            RecordedBody::Text(text) => Ok(text.as_bytes().to_vec()),
+            RecordedBody::Binary { base64 } => STANDARD.decode(base64),
+        }
+    }
+}
+
+impl Default for RecordedBody {
+    fn default() -> Self {
+        RecordedBody::Text(String::new())
+    }
+}
+
+/// A [Middleware] that saves every request and response into a [Cassette] file
+///
+/// The file is rewritten after each response, so it is complete even if the program stops early.
+/// Add the recorder as the last middleware to record requests as they are sent.
+#[derive(Debug)]
+pub struct Recorder {
+    path: PathBuf,
+    cassette: Mutex<Cassette>,
+}
+
+impl Recorder {
+    /// Creates a new [Recorder] that writes to the file at `path`
+    pub fn new(path: impl Into<PathBuf>) -> Recorder {
+        Recorder {
+            path: path.into(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// Get the requests that were recorded so far
+    pub fn get_cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Middleware for Recorder {
+    async fn after_response(
+        &self,
+        request: &TodoistRequest,
+        response: &mut TodoistResponse,
+    ) -> Result<(), TodoistAPIError> {
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette.interactions.push(Interaction {
+            request: RecordedRequest {
+                method: request.method.to_string(),
+                url: request.url.clone(),
+                headers: record_headers(&request.headers),
+                body: request.body.as_deref().map(RecordedBody::from_bytes),
+            },
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers: record_headers(&response.headers),
+                body: RecordedBody::from_bytes(&response.body),
+            },
+        });
+        cassette.save(&self.path).map_err(|e| {
+            APIParametersError {
+                message: format!("Could not save the cassette - {}", e),
+            }
+            .into()
+        })
+    }
+}
+
+/// A [Middleware] that answers requests with the responses of a [Cassette] instead of sending them
+///
+/// Each request is answered by the first unused interaction with the same method, URL and body.
+/// JSON bodies match if they contain the same values, regardless of formatting or key order.
+/// Requests without a matching interaction fail with [TodoistAPIError::APIParametersError].
+#[derive(Debug)]
+pub struct Replayer {
+    interactions: Mutex<Vec<Option<Interaction>>>,
+    match_bodies: bool,
+}
+
+impl Replayer {
+    /// Creates a new [Replayer] that replays the interactions of `cassette`
+    pub fn new(cassette: Cassette) -> Replayer {
+        Replayer {
+            interactions: Mutex::new(cassette.interactions.into_iter().map(Some).collect()),
+            match_bodies: true,
+        }
+    }
+
+    /// Sets whether the bodies of requests must match the recorded bodies (the default)
+    ///
+    /// Disable this to replay requests with generated bodies,
+    /// such as Sync commands (which contain random UUIDs) or file uploads (which contain a random boundary).
+    pub fn match_bodies(mut self, match_bodies: bool) -> Replayer {
+        self.match_bodies = match_bodies;
+        self
+    }
+
+    /// Creates a new [Replayer] that replays the [Cassette] stored at `path`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Replayer, CassetteError> {
+        Ok(Replayer::new(Cassette::load(path)?))
+    }
+
+    /// Get the number of interactions that were not replayed yet
+    pub fn get_remaining_count(&self) -> usize {
+        self.interactions.lock().unwrap().iter().flatten().count()
+    }
+}
+
+#[async_trait]
+impl Middleware for Replayer {
+    async fn before_request(
+        &self,
+        request: &mut TodoistRequest,
+    ) -> Result<Option<TodoistResponse>, TodoistAPIError> {
+        let method = request.method.to_string();
+        let interaction = self
+            .interactions
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|interaction| {
+                interaction.as_ref().is_some_and(|interaction| {
+                    interaction.request.method == method
+                        && interaction.request.url == request.url
+                        && (!self.match_bodies
+                            || bodies_match(
+                                interaction.request.body.as_ref(),
+                                request.body.as_deref(),
+                            ))
+                })
+            })
+            .and_then(Option::take)
+            .ok_or_else(|| APIParametersError {
+                message: format!(
+                    "No recorded interaction for {} {} with body {}",
+                    method,
+                    request.url,
+                    request
+                        .body
+                        .as_deref()
+                        .map(String::from_utf8_lossy)
+                        .unwrap_or_default()
+                ),
+            })?;
+        let response = interaction.response;
+        Ok(Some(TodoistResponse {
+            status: StatusCode::from_u16(response.status).map_err(|e| APIParametersError {
+                message: format!("Invalid recorded status code - {}", e),
+            })?,
+            headers: response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((
+                        HeaderName::try_from(name.as_str()).ok()?,
+                        HeaderValue::try_from(value.as_str()).ok()?,
+                    ))
+                })
+                .collect(),
+            body: response.body.to_bytes().map_err(|e| APIParametersError {
+                message: format!("Invalid recorded body - {}", e),
+            })?,
+        }))
+    }
+}
+
+fn bodies_match(recorded: Option<&RecordedBody>, body: Option<&[u8]>) -> bool {
+    let recorded = recorded.map(RecordedBody::to_bytes).transpose();
+    let Ok(recorded) = recorded else {
+        return false;
+    };
+    match (recorded.as_deref(), body) {
+        (Some(recorded), Some(body)) => {
+            let as_json = |bytes| serde_json::from_slice::<serde_json::Value>(bytes).ok();
+            match (as_json(recorded), as_json(body)) {
+                (Some(recorded), Some(body)) => recorded == body,
+                _ => recorded == body,
+            }
+        }
+        (recorded, body) => {
+            recorded.unwrap_or_default().is_empty() && body.unwrap_or_default().is_empty()
+        }
+    }
+}
+
+fn record_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) || value.is_sensitive() {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Errors for when a [Cassette] cannot be read or written
+#[derive(Debug)]
+pub enum CassetteError {
+    /// If the file could not be read or written
+    IOError(std::io::Error),
+    /// If the file does not contain a valid cassette
+    JSONError(serde_json::Error),
+}
+
+impl Display for CassetteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CassetteError::IOError(e) => write!(f, "Cassette IO error - {}", e),
+            CassetteError::JSONError(e) => write!(f, "Cassette JSON error - {}", e),
+        }
+    }
+}
+
+impl Error for CassetteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CassetteError::IOError(ref e) => Some(e),
+            CassetteError::JSONError(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for CassetteError {
+    fn from(value: std::io::Error) -> Self {
+        CassetteError::IOError(value)
+    }
+}
+
+impl From<serde_json::Error> for CassetteError {
+    fn from(value: serde_json::Error) -> Self {
+        CassetteError::JSONError(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::AUTHORIZATION;
+
+    use super::*;
+    use crate::todoist_config::TodoistConfig;
+
+    const PROJECTS_URL: &str = "https://api.todoist.com/rest/v2/projects";
+
+    #[test]
+    fn sensitive_headers_are_redacted() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, "Bearer 0123456789abcdef".parse().unwrap());
+        headers.insert("X-Request-Id", "1234".parse().unwrap());
+        let recorded = record_headers(&headers);
+        assert_eq!(recorded["authorization"], REDACTED);
+        assert_eq!(recorded["x-request-id"], "1234");
+    }
+
+    #[tokio::test]
+    async fn records_replayed_requests() {
+        let path = std::env::temp_dir().join(format!("cassette-{}.json", uuid::Uuid::new_v4()));
+        let cassette = Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest {
+                    method: "GET".into(),
+                    url: PROJECTS_URL.into(),
+                    headers: BTreeMap::new(),
+                    body: None,
+                },
+                response: RecordedResponse {
+                    status: 200,
+                    headers: BTreeMap::new(),
+                    body: RecordedBody::Text("[]".into()),
+                },
+            }],
+        };
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(Recorder::new(&path))
+            .with_middleware(Replayer::new(cassette.clone()));
+        assert!(crate::projects::get_all_projects(&config)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(Cassette::load(&path).unwrap(), cassette);
+        std::fs::remove_file(&path).unwrap();
+
+        // Each interaction is only replayed once
+        assert!(crate::projects::get_all_projects(&config).await.is_err());
+    }
+
+    #[test]
+    fn bodies_are_stored_without_losing_bytes() {
+        let body = RecordedBody::from_bytes(&[0xff, 0x00, 0x7b]);
+        assert!(matches!(body, RecordedBody::Binary { .. }));
+        assert_eq!(body.to_bytes().unwrap(), [0xff, 0x00, 0x7b]);
+        let json = serde_json::to_string(&body).unwrap();
+        assert_eq!(serde_json::from_str::<RecordedBody>(&json).unwrap(), body);
+        assert_eq!(
+            RecordedBody::from_bytes(b"{}"),
+            RecordedBody::Text("{}".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_only_match_interactions_with_the_same_body() {
+        let cassette = Cassette {
+            interactions: vec![Interaction {
+                request: RecordedRequest {
+                    method: "POST".into(),
+                    url: PROJECTS_URL.into(),
+                    headers: BTreeMap::new(),
+                    body: Some(RecordedBody::Text(r#"{ "name": "Shopping List" }"#.into())),
+                },
+                response: RecordedResponse {
+                    status: 200,
+                    headers: BTreeMap::new(),
+                    body: RecordedBody::Text(
+                        r#"{"id": "2203306141", "name": "Shopping List", "comment_count": 0,
+                        "order": 1, "color": "charcoal", "is_shared": false, "is_favorite": false,
+                        "is_inbox_project": false, "is_team_inbox": false, "view_style": "list",
+                        "url": "https://todoist.com/showProject?id=2203306141", "parent_id": null}"#
+                            .into(),
+                    ),
+                },
+            }],
+        };
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(Replayer::new(cassette.clone()));
+        let create = |name: &str| {
+            crate::projects::create_new_project(&config, name.into(), None, None, None, None)
+        };
+        assert!(create("Groceries").await.is_err());
+        assert_eq!(create("Shopping List").await.unwrap().id, "2203306141");
+
+        let config = TodoistConfig::new("0123456789abcdef")
+            .unwrap()
+            .with_middleware(Replayer::new(cassette).match_bodies(false));
+        crate::projects::create_new_project(&config, "Groceries".into(), None, None, None, None)
+            .await
+            .unwrap();
+    }
+}
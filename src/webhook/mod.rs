@@ -0,0 +1,252 @@
+//! Parsing and verification of Todoist webhook deliveries (<https://developer.todoist.com/sync/v9/#webhooks>)
+//!
+//! Todoist signs every webhook request body with the app's client secret.
+//! Use [parse_webhook] to verify the `X-Todoist-Hmac-SHA256` header and decode the payload
+//! into a [WebhookEvent].
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+#[cfg(feature = "webhook-server")]
+pub mod server;
+
+/// The header containing the base64-encoded HMAC-SHA256 signature of the request body
+pub const SIGNATURE_HEADER: &str = "X-Todoist-Hmac-SHA256";
+/// The header containing the unique ID of a webhook delivery (repeated when Todoist retries a delivery)
+pub const DELIVERY_ID_HEADER: &str = "X-Todoist-Delivery-ID";
+
+/// A webhook event sent by Todoist
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookEvent {
+    /// The name of the event (ex: `item:added`)
+    pub event_name: WebhookEventName,
+    /// The ID of the user that is the destination for the event
+    pub user_id: String,
+    /// The object that the event is about (ex: the added task for `item:added`)
+    ///
+    /// Use [WebhookEvent::data] to decode it into a concrete type
+    pub event_data: serde_json::Value,
+    /// The user that triggered the event
+    pub initiator: WebhookInitiator,
+    /// The date and time when the event was triggered
+    pub triggered_at: Option<String>,
+    /// The version number of the webhook configured in the App Management Console
+    pub version: String,
+}
+
+impl WebhookEvent {
+    /// Decodes the [event data](WebhookEvent::event_data) into `T`
+    pub fn data<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(&self.event_data)
+    }
+}
+
+/// The user that triggered a [WebhookEvent]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookInitiator {
+    /// The user's ID
+    pub id: String,
+    /// The user's email address
+    pub email: String,
+    /// The user's full name
+    pub full_name: String,
+    /// The ID of the user's avatar image ([None] if the user has no avatar)
+    pub image_id: Option<String>,
+    /// Whether the user has a premium subscription
+    pub is_premium: bool,
+}
+
+/// The names of the events that Todoist can send to a webhook
+#[allow(missing_docs)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEventName {
+    #[serde(rename = "item:added")]
+    ItemAdded,
+    #[serde(rename = "item:updated")]
+    ItemUpdated,
+    #[serde(rename = "item:deleted")]
+    ItemDeleted,
+    #[serde(rename = "item:completed")]
+    ItemCompleted,
+    #[serde(rename = "item:uncompleted")]
+    ItemUncompleted,
+    #[serde(rename = "note:added")]
+    NoteAdded,
+    #[serde(rename = "note:updated")]
+    NoteUpdated,
+    #[serde(rename = "note:deleted")]
+    NoteDeleted,
+    #[serde(rename = "project:added")]
+    ProjectAdded,
+    #[serde(rename = "project:updated")]
+    ProjectUpdated,
+    #[serde(rename = "project:deleted")]
+    ProjectDeleted,
+    #[serde(rename = "project:archived")]
+    ProjectArchived,
+    #[serde(rename = "project:unarchived")]
+    ProjectUnarchived,
+    #[serde(rename = "section:added")]
+    SectionAdded,
+    #[serde(rename = "section:updated")]
+    SectionUpdated,
+    #[serde(rename = "section:deleted")]
+    SectionDeleted,
+    #[serde(rename = "section:archived")]
+    SectionArchived,
+    #[serde(rename = "section:unarchived")]
+    SectionUnarchived,
+    #[serde(rename = "label:added")]
+    LabelAdded,
+    #[serde(rename = "label:deleted")]
+    LabelDeleted,
+    #[serde(rename = "label:updated")]
+    LabelUpdated,
+    #[serde(rename = "filter:added")]
+    FilterAdded,
+    #[serde(rename = "filter:deleted")]
+    FilterDeleted,
+    #[serde(rename = "filter:updated")]
+    FilterUpdated,
+    #[serde(rename = "reminder:fired")]
+    ReminderFired,
+    /// An event that is not known by this version of the crate
+    #[serde(other)]
+    Unknown,
+}
+
+/// Checks whether `signature` (the value of the [SIGNATURE_HEADER] header)
+/// is the valid signature of `body` for the given client secret
+pub fn verify_signature(client_secret: &str, body: &[u8], signature: &str) -> bool {
+    let Ok(signature) = STANDARD.decode(signature.trim()) else {
+        return false;
+    };
+    let mut mac = Hmac::<Sha256>::new_from_slice(client_secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(body);
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Creates the signature that Todoist would send in the [SIGNATURE_HEADER] header for `body`
+pub fn sign_body(client_secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(client_secret.as_bytes())
+        .expect("HMAC can take a key of any size");
+    mac.update(body);
+    STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Verifies the signature of a webhook delivery and parses its body into a [WebhookEvent]
+///
+/// # Arguments
+/// * `client_secret` - The client secret of the Todoist app that the webhook belongs to
+/// * `body` - The raw request body
+/// * `signature` - The value of the [SIGNATURE_HEADER] header ([None] if it was missing)
+pub fn parse_webhook(
+    client_secret: &str,
+    body: &[u8],
+    signature: Option<&str>,
+) -> Result<WebhookEvent, WebhookError> {
+    let signature = signature.ok_or(WebhookError::MissingSignature)?;
+    if !verify_signature(client_secret, body, signature) {
+        return Err(WebhookError::InvalidSignature);
+    }
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// Errors for when a webhook delivery cannot be parsed
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The [SIGNATURE_HEADER] header was missing
+    MissingSignature,
+    /// The signature did not match the request body
+    InvalidSignature,
+    /// The request body was not a valid webhook payload
+    PayloadParseError(serde_json::Error),
+}
+
+impl Display for WebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::MissingSignature => write!(f, "Missing {} header", SIGNATURE_HEADER),
+            WebhookError::InvalidSignature => write!(f, "Invalid webhook signature"),
+            WebhookError::PayloadParseError(e) => write!(f, "Invalid webhook payload - {}", e),
+        }
+    }
+}
+
+impl Error for WebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WebhookError::PayloadParseError(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for WebhookError {
+    fn from(value: serde_json::Error) -> Self {
+        WebhookError::PayloadParseError(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "0123456789abcdef";
+    const ITEM_ADDED: &str = r#"{
+        "event_name": "item:added",
+        "user_id": "2671355",
+        "event_data": {
+            "id": "2995104339",
+            "content": "Buy Milk",
+            "project_id": "2203306141"
+        },
+        "initiator": {
+            "email": "alice@example.com",
+            "full_name": "Alice",
+            "id": "2671355",
+            "image_id": "ad38375bdb094286af59f1eab36d8f20",
+            "is_premium": true
+        },
+        "triggered_at": "2021-02-10T10:39:38.000000Z",
+        "version": "10"
+    }"#;
+
+    #[test]
+    fn parse_signed_webhook() {
+        let signature = sign_body(SECRET, ITEM_ADDED.as_bytes());
+        let event = parse_webhook(SECRET, ITEM_ADDED.as_bytes(), Some(&signature)).unwrap();
+        assert_eq!(event.event_name, WebhookEventName::ItemAdded);
+        assert_eq!(event.initiator.full_name, "Alice");
+        assert_eq!(event.event_data["content"], "Buy Milk");
+    }
+
+    #[test]
+    fn reject_invalid_signature() {
+        let signature = sign_body("another secret", ITEM_ADDED.as_bytes());
+        assert!(matches!(
+            parse_webhook(SECRET, ITEM_ADDED.as_bytes(), Some(&signature)),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            parse_webhook(SECRET, ITEM_ADDED.as_bytes(), None),
+            Err(WebhookError::MissingSignature)
+        ));
+    }
+
+    #[test]
+    fn parse_unknown_event_name() {
+        let body = ITEM_ADDED.replace("item:added", "item:teleported");
+        let signature = sign_body(SECRET, body.as_bytes());
+        let event = parse_webhook(SECRET, body.as_bytes(), Some(&signature)).unwrap();
+        assert_eq!(event.event_name, WebhookEventName::Unknown);
+    }
+}
@@ -0,0 +1,400 @@
+//! A small embeddable HTTP server that receives Todoist webhook deliveries
+//!
+//! Every delivery is [verified](super::parse_webhook), deduplicated using the
+//! [X-Todoist-Delivery-ID](super::DELIVERY_ID_HEADER) header and then dispatched to the
+//! handlers registered for its [event name](WebhookEventName).
+//! A delivery is remembered before its handlers are called, so a redelivery that arrives
+//! while the first delivery is still being handled is ignored. It is forgotten again if one
+//! of its handlers panicked, so that it is processed again when Todoist retries it.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use todoist_rest_api::webhook::server::WebhookServer;
+//! use todoist_rest_api::webhook::WebhookEventName;
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//! WebhookServer::new("client secret")
+//!     .on(WebhookEventName::ItemAdded, |event| async move {
+//!         println!("Task added by {}", event.initiator.full_name);
+//!     })
+//!     .serve(listener)
+//!     .await
+//! # }
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::Infallible;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+
+use super::{parse_webhook, WebhookEvent, WebhookEventName, DELIVERY_ID_HEADER, SIGNATURE_HEADER};
+
+/// The default number of delivery IDs remembered for deduplication
+pub const DEFAULT_DEDUPLICATION_CAPACITY: usize = 1024;
+
+/// The default maximum size of a delivery body in bytes (1 MiB)
+pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
+
+type Handler = Arc<dyn Fn(WebhookEvent) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
+
+/// A webhook receiver that dispatches [events](WebhookEvent) to async handlers
+pub struct WebhookServer {
+    client_secret: String,
+    handlers: HashMap<WebhookEventName, Vec<Handler>>,
+    fallback_handlers: Vec<Handler>,
+    deduplication_capacity: usize,
+    max_body_size: usize,
+}
+
+impl WebhookServer {
+    /// Creates a new [WebhookServer] that verifies deliveries with the app's client secret
+    pub fn new(client_secret: impl Into<String>) -> WebhookServer {
+        WebhookServer {
+            client_secret: client_secret.into(),
+            handlers: HashMap::new(),
+            fallback_handlers: Vec::new(),
+            deduplication_capacity: DEFAULT_DEDUPLICATION_CAPACITY,
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+        }
+    }
+
+    /// Registers a handler that is called for every event named `event_name`
+    pub fn on<F, Fut>(mut self, event_name: WebhookEventName, handler: F) -> WebhookServer
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.handlers
+            .entry(event_name)
+            .or_default()
+            .push(Arc::new(move |event| Box::pin(handler(event))));
+        self
+    }
+
+    /// Registers a handler that is called for every event, regardless of its name
+    pub fn on_any<F, Fut>(mut self, handler: F) -> WebhookServer
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.fallback_handlers
+            .push(Arc::new(move |event| Box::pin(handler(event))));
+        self
+    }
+
+    /// Sets how many delivery IDs are remembered to ignore redelivered events
+    /// (defaults to [DEFAULT_DEDUPLICATION_CAPACITY])
+    pub fn deduplication_capacity(mut self, capacity: usize) -> WebhookServer {
+        self.deduplication_capacity = capacity;
+        self
+    }
+
+    /// Sets the maximum size of a delivery body in bytes (defaults to [DEFAULT_MAX_BODY_SIZE])
+    ///
+    /// Larger deliveries are rejected with `413 Payload Too Large` before their signature is checked.
+    pub fn max_body_size(mut self, max_body_size: usize) -> WebhookServer {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Accepts connections from `listener` until an I/O error occurs
+    ///
+    /// Handlers are awaited before the response is sent, so they should finish quickly
+    /// (Todoist considers slow deliveries as failed and retries them).
+    pub async fn serve(self, listener: TcpListener) -> std::io::Result<()> {
+        let state = Arc::new(ServerState {
+            deliveries: Mutex::new(DeliveryLog::new(self.deduplication_capacity)),
+            server: self,
+        });
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let state = state.clone();
+            tokio::spawn(async move {
+                let service = service_fn(move |request| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(state.handle(request).await) }
+                });
+                // Connection errors only affect a single delivery, which Todoist will retry
+                let _ = http1::Builder::new()
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+            });
+        }
+    }
+}
+
+struct ServerState {
+    server: WebhookServer,
+    deliveries: Mutex<DeliveryLog>,
+}
+
+impl ServerState {
+    async fn handle(&self, request: Request<Incoming>) -> Response<Full<Bytes>> {
+        if request.method() != Method::POST {
+            return create_response(StatusCode::METHOD_NOT_ALLOWED);
+        }
+        let signature = header_value(&request, SIGNATURE_HEADER);
+        let delivery_id = header_value(&request, DELIVERY_ID_HEADER);
+        let body = Limited::new(request.into_body(), self.server.max_body_size);
+        let body = match body.collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(error) if error.is::<LengthLimitError>() => {
+                return create_response(StatusCode::PAYLOAD_TOO_LARGE)
+            }
+            Err(_) => return create_response(StatusCode::BAD_REQUEST),
+        };
+        let event = match parse_webhook(&self.server.client_secret, &body, signature.as_deref()) {
+            Ok(event) => event,
+            Err(super::WebhookError::PayloadParseError(_)) => {
+                return create_response(StatusCode::BAD_REQUEST)
+            }
+            Err(_) => return create_response(StatusCode::UNAUTHORIZED),
+        };
+        // The delivery is recorded before the handlers run, so that concurrent redeliveries are ignored
+        if let Some(delivery_id) = &delivery_id {
+            if !self.deliveries.lock().unwrap().insert(delivery_id.clone()) {
+                return create_response(StatusCode::OK);
+            }
+        }
+        let handlers = self
+            .server
+            .handlers
+            .get(&event.event_name)
+            .into_iter()
+            .flatten()
+            .chain(&self.server.fallback_handlers);
+        for handler in handlers {
+            // A panicking handler fails the delivery, so that Todoist delivers it again
+            if tokio::spawn(handler(event.clone())).await.is_err() {
+                if let Some(delivery_id) = &delivery_id {
+                    self.deliveries.lock().unwrap().remove(delivery_id);
+                }
+                return create_response(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+        create_response(StatusCode::OK)
+    }
+}
+
+/// The most recent delivery IDs, oldest first
+struct DeliveryLog {
+    capacity: usize,
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl DeliveryLog {
+    fn new(capacity: usize) -> DeliveryLog {
+        DeliveryLog {
+            capacity,
+            ids: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Returns `false` if the delivery ID was already seen
+    fn insert(&mut self, delivery_id: String) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if self.ids.contains(&delivery_id) {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.ids.remove(&oldest);
+            }
+        }
+        self.ids.insert(delivery_id.clone());
+        self.order.push_back(delivery_id);
+        true
+    }
+
+    /// Forgets a delivery ID, so that the delivery is processed again
+    fn remove(&mut self, delivery_id: &str) {
+        if self.ids.remove(delivery_id) {
+            self.order.retain(|id| id != delivery_id);
+        }
+    }
+}
+
+fn header_value(request: &Request<Incoming>, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
+}
+
+fn create_response(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::new()));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::webhook::sign_body;
+
+    const SECRET: &str = "0123456789abcdef";
+    const NOTE_ADDED: &str = r#"{
+        "event_name": "note:added",
+        "user_id": "2671355",
+        "event_data": {"id": "2992679862", "content": "Hello"},
+        "initiator": {
+            "email": "alice@example.com",
+            "full_name": "Alice",
+            "id": "2671355",
+            "image_id": null,
+            "is_premium": false
+        },
+        "triggered_at": "2021-02-10T10:39:38.000000Z",
+        "version": "10"
+    }"#;
+
+    async fn start(server: WebhookServer) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(server.serve(listener));
+        address
+    }
+
+    async fn post(address: SocketAddr, body: &str, signature: &str, delivery_id: &str) -> u16 {
+        reqwest::Client::new()
+            .post(format!("http://{}/", address))
+            .header(SIGNATURE_HEADER, signature)
+            .header(DELIVERY_ID_HEADER, delivery_id)
+            .body(body.to_string())
+            .send()
+            .await
+            .unwrap()
+            .status()
+            .as_u16()
+    }
+
+    #[tokio::test]
+    async fn dispatches_and_deduplicates_deliveries() {
+        let note_count = Arc::new(AtomicUsize::new(0));
+        let any_count = Arc::new(AtomicUsize::new(0));
+        let server = WebhookServer::new(SECRET)
+            .on(WebhookEventName::NoteAdded, {
+                let note_count = note_count.clone();
+                move |event| {
+                    let note_count = note_count.clone();
+                    async move {
+                        assert_eq!(event.event_data["content"], "Hello");
+                        note_count.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            })
+            .on(WebhookEventName::ItemAdded, |_| async {
+                panic!("wrong event")
+            })
+            .on_any({
+                let any_count = any_count.clone();
+                move |_| {
+                    let any_count = any_count.clone();
+                    async move {
+                        any_count.fetch_add(1, Ordering::SeqCst);
+                    }
+                }
+            });
+        let address = start(server).await;
+        let signature = sign_body(SECRET, NOTE_ADDED.as_bytes());
+
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 200);
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 200);
+        assert_eq!(post(address, NOTE_ADDED, &signature, "2").await, 200);
+        assert_eq!(note_count.load(Ordering::SeqCst), 2);
+        assert_eq!(any_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_deliveries() {
+        let address = start(WebhookServer::new(SECRET)).await;
+        let wrong_signature = sign_body("another secret", NOTE_ADDED.as_bytes());
+        assert_eq!(post(address, NOTE_ADDED, &wrong_signature, "1").await, 401);
+        let invalid_body = "{}";
+        let signature = sign_body(SECRET, invalid_body.as_bytes());
+        assert_eq!(post(address, invalid_body, &signature, "2").await, 400);
+    }
+
+    #[tokio::test]
+    async fn failed_deliveries_are_not_deduplicated() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let server = WebhookServer::new(SECRET).on_any({
+            let attempts = attempts.clone();
+            move |_| {
+                let attempts = attempts.clone();
+                async move {
+                    if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        panic!("handler failed");
+                    }
+                }
+            }
+        });
+        let address = start(server).await;
+        let signature = sign_body(SECRET, NOTE_ADDED.as_bytes());
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 500);
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 200);
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 200);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn concurrent_redeliveries_are_handled_once() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let server = WebhookServer::new(SECRET).on_any({
+            let attempts = attempts.clone();
+            move |_| {
+                let attempts = attempts.clone();
+                async move {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                }
+            }
+        });
+        let address = start(server).await;
+        let signature = sign_body(SECRET, NOTE_ADDED.as_bytes());
+        let (first, second) = tokio::join!(
+            post(address, NOTE_ADDED, &signature, "1"),
+            post(address, NOTE_ADDED, &signature, "1")
+        );
+        assert_eq!((first, second), (200, 200));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_large_bodies_before_checking_them() {
+        let address = start(WebhookServer::new(SECRET).max_body_size(16)).await;
+        let signature = sign_body(SECRET, NOTE_ADDED.as_bytes());
+        assert_eq!(post(address, NOTE_ADDED, &signature, "1").await, 413);
+    }
+
+    #[test]
+    fn delivery_log_forgets_oldest_ids() {
+        let mut log = DeliveryLog::new(2);
+        assert!(log.insert("1".into()));
+        assert!(log.insert("2".into()));
+        assert!(!log.insert("1".into()));
+        assert!(log.insert("3".into()));
+        assert!(log.insert("1".into()));
+        assert!(!log.insert("3".into()));
+        log.remove("3");
+        assert!(log.insert("3".into()));
+        assert_eq!(log.order, ["1", "3"]);
+    }
+}
@@ -0,0 +1,232 @@
+//! Replays the responses in `tests/fixtures/api.json` through every API function
+//!
+//! The cassette is hand-written from the examples of the Todoist API documentation,
+//! not recorded from the real API (see `tests/fixtures/README.md`).
+
+#![cfg(feature = "vcr")]
+
+use std::sync::Arc;
+
+use todoist_rest_api::comments::TaskOrProjectID;
+use todoist_rest_api::todoist_config::TodoistConfig;
+use todoist_rest_api::vcr::Replayer;
+use todoist_rest_api::{comments, labels, projects, sections, tasks};
+
+const CASSETTE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/api.json");
+
+fn create_config() -> (TodoistConfig, Arc<Replayer>) {
+    let replayer = Arc::new(Replayer::from_file(CASSETTE).unwrap());
+    let config = TodoistConfig::new("0123456789abcdef")
+        .unwrap()
+        .with_middleware(replayer.clone());
+    (config, replayer)
+}
+
+#[tokio::test]
+async fn replay_projects() {
+    let (config, _) = create_config();
+    let project_id = "2203306141".to_string();
+    assert_eq!(projects::get_all_projects(&config).await.unwrap().len(), 1);
+    let project =
+        projects::create_new_project(&config, "Shopping List".into(), None, None, None, None)
+            .await
+            .unwrap();
+    assert_eq!(project.id, project_id);
+    assert!(project.is_shared);
+    assert_eq!(
+        projects::get_project(&config, project_id.clone())
+            .await
+            .unwrap()
+            .url,
+        project.url
+    );
+    let project = projects::update_project(
+        &config,
+        project_id.clone(),
+        Some("Things To Buy".into()),
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(project.name, "Things To Buy");
+    let collaborators = projects::get_all_collaborators(&config, project_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(collaborators[1].name, "Bob");
+    projects::delete_project(&config, project_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn replay_sections() {
+    let (config, _) = create_config();
+    let section_id = "7025".to_string();
+    let sections = sections::get_all_sections(&config, Some("2203306141".into()))
+        .await
+        .unwrap();
+    assert_eq!(sections[0].name, "Groceries");
+    let section =
+        sections::create_new_section(&config, "2203306141".into(), "Groceries".into(), None)
+            .await
+            .unwrap();
+    assert_eq!(section.id, section_id);
+    sections::get_section(&config, section_id.clone())
+        .await
+        .unwrap();
+    let section = sections::update_section(&config, section_id.clone(), "Supermarket".into())
+        .await
+        .unwrap();
+    assert_eq!(section.name, "Supermarket");
+    sections::delete_section(&config, section_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn replay_tasks() {
+    let (config, _) = create_config();
+    let task_id = "2995104339".to_string();
+    let tasks = tasks::get_active_tasks(
+        &config,
+        Some("2203306141".into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(tasks[0].labels, ["Food", "Shopping"]);
+    let task = tasks::create_new_task(
+        &config,
+        "Buy Milk".into(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(vec!["Food".into(), "Shopping".into()]),
+        None,
+        Some("tomorrow at 12".into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(task.due.unwrap().timezone.unwrap(), "Europe/Moscow");
+    tasks::get_active_task(&config, task_id.clone())
+        .await
+        .unwrap();
+    let task = tasks::update_task(
+        &config,
+        task_id.clone(),
+        Some("Buy Coffee".into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(task.content, "Buy Coffee");
+    tasks::close_task(&config, task_id.clone()).await.unwrap();
+    tasks::reopen_task(&config, task_id.clone()).await.unwrap();
+    tasks::delete_task(&config, task_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn replay_labels() {
+    let (config, _) = create_config();
+    let label_id = "2156154810".to_string();
+    assert_eq!(
+        labels::get_all_personal_labels(&config)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    let label = labels::create_new_personal_label(&config, "Food".into(), None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(label.id, label_id);
+    labels::get_personal_label(&config, label_id.clone())
+        .await
+        .unwrap();
+    let label = labels::update_personal_label(
+        &config,
+        label_id.clone(),
+        Some("Drinks".into()),
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(label.name, "Drinks");
+    labels::delete_personal_label(&config, label_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        labels::get_all_shared_labels(&config, Some(true))
+            .await
+            .unwrap(),
+        ["Label1", "Label2"]
+    );
+    labels::rename_shared_labels(&config, "Label1".into(), "Label3".into())
+        .await
+        .unwrap();
+    labels::remove_shared_labels(&config, "Label2".into())
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn replay_comments() {
+    let (config, _) = create_config();
+    let task_id = TaskOrProjectID::Task("2995104339".into());
+    let comment_id = "2992679862".to_string();
+    let comments = comments::get_all_comments(&config, &task_id).await.unwrap();
+    assert_eq!(
+        comments[0].attachment.as_ref().unwrap().file_name,
+        "File.pdf"
+    );
+    let comment = comments::create_new_comment(
+        &config,
+        &task_id,
+        "Need one bottle of milk".into(),
+        None,
+        Some(vec!["2671362".into()]),
+    )
+    .await
+    .unwrap();
+    assert_eq!(comment.id, comment_id);
+    assert_eq!(comment.posted_at, "2016-09-22T07:00:00.000000Z");
+    comments::get_comment(&config, comment_id.clone())
+        .await
+        .unwrap();
+    let comment = comments::update_comment(
+        &config,
+        comment_id.clone(),
+        "Need two bottles of milk".into(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(comment.content, "Need two bottles of milk");
+    comments::delete_comment(&config, comment_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn unrecorded_requests_fail() {
+    let (config, replayer) = create_config();
+    let remaining = replayer.get_remaining_count();
+    assert!(projects::get_project(&config, "0".into()).await.is_err());
+    assert_eq!(replayer.get_remaining_count(), remaining);
+}